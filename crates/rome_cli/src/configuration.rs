@@ -0,0 +1,677 @@
+//! Discovery and loading of the `rome.json` configuration file.
+//!
+//! The configuration file is looked up from the working directory upward, and looks like this:
+//!
+//! ```json
+//! {
+//!     "formatter": {
+//!         "indentStyle": "space",
+//!         "indentSize": 4,
+//!         "lineWidth": 100,
+//!         "lineEnding": "lf",
+//!         "quoteStyle": "single",
+//!         "semicolons": "as-needed",
+//!         "trailingComma": "all",
+//!         "ignore": ["dist/**", "**/*.min.js"]
+//!     },
+//!     "javascript": {
+//!         "formatter": {
+//!             "sourceType": "module"
+//!         }
+//!     },
+//!     "json": {
+//!         "formatter": {
+//!             "indentSize": 2
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The `javascript`, `typescript` and `json` sections override the `formatter` settings for
+//! the files of that language. The `ignore` globs are relative to the directory of the configuration file.
+//! An `indentSize` without an `indentStyle` sets an indent of that many spaces.
+//!
+//! Like `tsconfig.json`, the file may contain comments and trailing commas. The keys and the strings
+//! must be JSON strings: in double quotes, with the escape sequences of JSON.
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use rome_core::file_handlers::Language;
+use rome_formatter::{
+	FormatOptions, IndentStyle, LineEnding, QuoteStyle, Semicolons, SourceType, TrailingComma,
+};
+use rslint_errors::file::SimpleFile;
+use rslint_errors::{Diagnostic, Emitter};
+use rslint_parser::ast::{
+	JsAnyExpression, JsAnyLiteralExpression, JsAnyObjectMember, JsAnyObjectMemberName,
+};
+use rslint_parser::{parse_expr, AstNode, TextRange};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Name of the configuration file
+pub(crate) const CONFIGURATION_FILE: &str = "rome.json";
+
+const ROOT_KEYS: &[&str] = &["formatter", "javascript", "typescript", "json"];
+const FORMATTER_KEYS: &[&str] = &[
+	"indentStyle",
+	"indentSize",
+	"lineWidth",
+	"lineEnding",
+	"quoteStyle",
+	"semicolons",
+	"trailingComma",
+	"sourceType",
+	"ignore",
+];
+const LANGUAGE_FORMATTER_KEYS: &[&str] = &[
+	"indentStyle",
+	"indentSize",
+	"lineWidth",
+	"lineEnding",
+	"quoteStyle",
+	"semicolons",
+	"trailingComma",
+	"sourceType",
+];
+const LANGUAGE_KEYS: &[&str] = &["formatter"];
+
+/// Formatting settings, a `None` value means that the setting isn't set at this level
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct FormatterSettings {
+	pub(crate) indent_style: Option<IndentStyle>,
+	pub(crate) indent_size: Option<u8>,
+	pub(crate) line_width: Option<u16>,
+	pub(crate) line_ending: Option<LineEnding>,
+	pub(crate) quote_style: Option<QuoteStyle>,
+	pub(crate) semicolons: Option<Semicolons>,
+	pub(crate) trailing_comma: Option<TrailingComma>,
+	pub(crate) source_type: Option<SourceType>,
+	pub(crate) format_with_errors: Option<bool>,
+	pub(crate) format_embedded_json: Option<bool>,
+}
+
+impl FormatterSettings {
+	/// Returns the settings of `self`, overridden by the ones that are set in `other`
+	fn merge(&self, other: &FormatterSettings) -> FormatterSettings {
+		FormatterSettings {
+			indent_style: other
+				.indent_style
+				.clone()
+				.or_else(|| self.indent_style.clone()),
+			indent_size: other.indent_size.or(self.indent_size),
+			line_width: other.line_width.or(self.line_width),
+			line_ending: other.line_ending.or(self.line_ending),
+			quote_style: other.quote_style.or(self.quote_style),
+			semicolons: other.semicolons.or(self.semicolons),
+			trailing_comma: other.trailing_comma.or(self.trailing_comma),
+			source_type: other.source_type.or(self.source_type),
+			format_with_errors: other.format_with_errors.or(self.format_with_errors),
+			format_embedded_json: other.format_embedded_json.or(self.format_embedded_json),
+		}
+	}
+
+	fn to_format_options(&self) -> FormatOptions {
+		let defaults = FormatOptions::default();
+
+		FormatOptions {
+			indent_style: match (&self.indent_style, self.indent_size) {
+				(Some(IndentStyle::Space(_)), size) => IndentStyle::Space(size.unwrap_or(2)),
+				// The size of the indent tells that it's made of spaces
+				(None, Some(size)) => IndentStyle::Space(size),
+				(Some(IndentStyle::Tab), _) | (None, None) => IndentStyle::Tab,
+			},
+			line_width: self.line_width.unwrap_or(defaults.line_width),
+			line_ending: self.line_ending.unwrap_or(defaults.line_ending),
+			quote_style: self.quote_style.unwrap_or(defaults.quote_style),
+			semicolons: self.semicolons.unwrap_or(defaults.semicolons),
+			trailing_comma: self.trailing_comma.unwrap_or(defaults.trailing_comma),
+			source_type: self.source_type.unwrap_or(defaults.source_type),
+			format_with_errors: self
+				.format_with_errors
+				.unwrap_or(defaults.format_with_errors),
+			format_embedded_json: self
+				.format_embedded_json
+				.unwrap_or(defaults.format_embedded_json),
+		}
+	}
+}
+
+/// The settings loaded from a `rome.json` file
+#[derive(Debug, Default)]
+pub(crate) struct Configuration {
+	/// The directory that contains the configuration file
+	base_directory: PathBuf,
+	formatter: FormatterSettings,
+	javascript: FormatterSettings,
+	typescript: FormatterSettings,
+	json: FormatterSettings,
+	ignore: Option<GlobSet>,
+}
+
+impl Configuration {
+	/// Overrides the settings of all the languages with the ones that are set in `settings`,
+	/// for example the options passed on the command line
+	pub(crate) fn override_formatter(&mut self, settings: &FormatterSettings) {
+		self.formatter = self.formatter.merge(settings);
+		self.javascript = self.javascript.merge(settings);
+		self.typescript = self.typescript.merge(settings);
+		self.json = self.json.merge(settings);
+	}
+
+	/// Returns the options used to format the files written in `language`
+	pub(crate) fn format_options(&self, language: Language) -> FormatOptions {
+		let settings = match language {
+			Language::Js => self.formatter.merge(&self.javascript),
+			Language::Ts => self.formatter.merge(&self.typescript),
+			Language::Json => self.formatter.merge(&self.json),
+			Language::Unknown => self.formatter.clone(),
+		};
+		settings.to_format_options()
+	}
+
+	/// Returns `true` if the file at `path` matches one of the `ignore` globs
+	pub(crate) fn is_ignored(&self, path: &Path) -> bool {
+		let ignore = match &self.ignore {
+			Some(ignore) => ignore,
+			None => return false,
+		};
+
+		// The globs are relative to the directory of the configuration file
+		let relative_path = fs::canonicalize(path).ok().and_then(|path| {
+			path.strip_prefix(&self.base_directory)
+				.ok()
+				.map(Path::to_path_buf)
+		});
+
+		match relative_path {
+			Some(relative_path) => ignore.is_match(relative_path),
+			None => ignore.is_match(path),
+		}
+	}
+}
+
+/// Reasons why the configuration file couldn't be loaded
+#[derive(Debug)]
+pub(crate) enum ConfigurationError {
+	/// The file couldn't be read
+	Io(PathBuf, io::Error),
+	/// The file contains errors, described by the diagnostics
+	Invalid(SimpleFile, Vec<Diagnostic>),
+}
+
+impl ConfigurationError {
+	/// Prints the error on the standard error
+	pub(crate) fn report(&self) {
+		match self {
+			ConfigurationError::Io(path, err) => {
+				eprintln!("error: cannot read \"{}\": {}", path.display(), err)
+			}
+			ConfigurationError::Invalid(file, diagnostics) => {
+				let mut emitter = Emitter::new(file);
+				for diagnostic in diagnostics {
+					// Nothing else can be done if stderr can't be written
+					let _ = emitter.emit_stderr(diagnostic, false);
+				}
+			}
+		}
+	}
+}
+
+/// Returns the path of the first configuration file found in `directory` or its ancestors
+pub(crate) fn find_configuration_file(directory: &Path) -> Option<PathBuf> {
+	directory
+		.ancestors()
+		.map(|directory| directory.join(CONFIGURATION_FILE))
+		.find(|path| path.is_file())
+}
+
+/// Loads the configuration file that applies to `directory`.
+///
+/// Returns the default configuration if there's no configuration file.
+pub(crate) fn load_configuration(directory: &Path) -> Result<Configuration, ConfigurationError> {
+	let path = match find_configuration_file(directory) {
+		Some(path) => path,
+		None => return Ok(Configuration::default()),
+	};
+
+	let source =
+		fs::read_to_string(&path).map_err(|err| ConfigurationError::Io(path.clone(), err))?;
+	let base_directory = path
+		.parent()
+		.and_then(|directory| fs::canonicalize(directory).ok())
+		.unwrap_or_default();
+
+	parse_configuration(&source, 0, base_directory).map_err(|diagnostics| {
+		ConfigurationError::Invalid(
+			SimpleFile::new(path.display().to_string(), source),
+			diagnostics,
+		)
+	})
+}
+
+/// Parses the content of a configuration file.
+///
+/// Returns the diagnostics of the syntax errors and invalid settings if the content isn't valid.
+pub(crate) fn parse_configuration(
+	source: &str,
+	file_id: usize,
+	base_directory: PathBuf,
+) -> Result<Configuration, Vec<Diagnostic>> {
+	let parse = parse_expr(source, file_id);
+	if !parse.errors().is_empty() {
+		return Err(parse.errors().to_vec());
+	}
+
+	let mut parser = ConfigurationParser {
+		file_id,
+		diagnostics: Vec::new(),
+	};
+	let mut configuration = Configuration {
+		base_directory,
+		..Configuration::default()
+	};
+
+	match parse.syntax().descendants().find_map(JsAnyExpression::cast) {
+		Some(root) => parser.parse_root(&root, &mut configuration),
+		None => {
+			return Err(vec![Diagnostic::error(
+				file_id,
+				"Configuration",
+				"the configuration must be an object",
+			)])
+		}
+	}
+
+	if parser.diagnostics.is_empty() {
+		Ok(configuration)
+	} else {
+		Err(parser.diagnostics)
+	}
+}
+
+/// Validates the JSON tree of a configuration file, and collects a diagnostic for every invalid setting
+struct ConfigurationParser {
+	file_id: usize,
+	diagnostics: Vec<Diagnostic>,
+}
+
+impl ConfigurationParser {
+	fn parse_root(&mut self, root: &JsAnyExpression, configuration: &mut Configuration) {
+		for (key, key_range, value) in self.members(root) {
+			match key.as_str() {
+				"formatter" => {
+					configuration.ignore =
+						self.parse_formatter(&value, &mut configuration.formatter, FORMATTER_KEYS)
+				}
+				"javascript" => self.parse_language(&value, &mut configuration.javascript),
+				"typescript" => self.parse_language(&value, &mut configuration.typescript),
+				"json" => self.parse_language(&value, &mut configuration.json),
+				_ => self.unknown_key(&key, key_range, ROOT_KEYS),
+			}
+		}
+	}
+
+	fn parse_language(&mut self, value: &JsAnyExpression, settings: &mut FormatterSettings) {
+		for (key, key_range, value) in self.members(value) {
+			match key.as_str() {
+				"formatter" => {
+					self.parse_formatter(&value, settings, LANGUAGE_FORMATTER_KEYS);
+				}
+				_ => self.unknown_key(&key, key_range, LANGUAGE_KEYS),
+			}
+		}
+	}
+
+	/// Parses the settings of a `formatter` section and returns the ignore globs, if any
+	fn parse_formatter(
+		&mut self,
+		value: &JsAnyExpression,
+		settings: &mut FormatterSettings,
+		allowed_keys: &[&str],
+	) -> Option<GlobSet> {
+		let mut ignore = None;
+		let mut indent_size = None;
+
+		for (key, key_range, value) in self.members(value) {
+			match key.as_str() {
+				"indentStyle" => {
+					settings.indent_style = self.keyword(&value, "expected \"tab\" or \"space\"")
+				}
+				"indentSize" => {
+					settings.indent_size = self.number(&value);
+					indent_size = Some(value);
+				}
+				"lineWidth" => settings.line_width = self.number(&value),
+				"lineEnding" => {
+					settings.line_ending =
+						self.keyword(&value, "expected \"lf\", \"crlf\", \"cr\" or \"auto\"")
+				}
+				"quoteStyle" => {
+					settings.quote_style = self.keyword(&value, "expected \"double\" or \"single\"")
+				}
+				"semicolons" => {
+					settings.semicolons =
+						self.keyword(&value, "expected \"always\" or \"as-needed\"")
+				}
+				"trailingComma" => {
+					settings.trailing_comma =
+						self.keyword(&value, "expected \"none\", \"es5\" or \"all\"")
+				}
+				"sourceType" => {
+					settings.source_type =
+						self.keyword(&value, "expected \"script\", \"module\" or \"auto\"")
+				}
+				"ignore" if allowed_keys.contains(&"ignore") => ignore = self.globs(&value),
+				_ => self.unknown_key(&key, key_range, allowed_keys),
+			}
+		}
+
+		if let (Some(IndentStyle::Tab), Some(indent_size)) = (&settings.indent_style, indent_size) {
+			self.invalid_value(
+				&indent_size,
+				"the indent size only applies to an indent made of spaces",
+			);
+		}
+
+		ignore
+	}
+
+	/// Returns the name, the range of the name and the value of the members of an object
+	fn members(&mut self, value: &JsAnyExpression) -> Vec<(String, TextRange, JsAnyExpression)> {
+		let object = match value {
+			JsAnyExpression::JsObjectExpression(object) => object,
+			_ => {
+				self.invalid_value(value, "expected an object");
+				return Vec::new();
+			}
+		};
+
+		let mut members = Vec::new();
+		for member in object.members().iter().flatten() {
+			let property = match member {
+				JsAnyObjectMember::JsPropertyObjectMember(property) => property,
+				member => {
+					self.diagnostics.push(
+						Diagnostic::error(self.file_id, "Configuration", "invalid member")
+							.primary(member.syntax().text_trimmed_range(), "expected a property"),
+					);
+					continue;
+				}
+			};
+
+			let name = match property.name() {
+				Ok(JsAnyObjectMemberName::JsLiteralMemberName(name)) => name,
+				_ => continue,
+			};
+			let value = match property.value() {
+				Ok(value) => value,
+				Err(_) => continue,
+			};
+
+			let key = name.value().ok().and_then(|token| {
+				let key = json_string(token.text_trimmed());
+				if key.is_none() {
+					self.diagnostics.push(
+						Diagnostic::error(self.file_id, "Configuration", "invalid key").primary(
+							name.syntax().text_trimmed_range(),
+							"expected a double quoted string",
+						),
+					);
+				}
+				key
+			});
+
+			if let Some(key) = key {
+				members.push((key, name.syntax().text_trimmed_range(), value));
+			}
+		}
+
+		members
+	}
+
+	fn string(&mut self, value: &JsAnyExpression) -> Option<String> {
+		let string = match value {
+			JsAnyExpression::JsAnyLiteralExpression(
+				JsAnyLiteralExpression::JsStringLiteralExpression(string),
+			) => string
+				.value_token()
+				.ok()
+				.and_then(|token| json_string(token.text_trimmed())),
+			_ => None,
+		};
+
+		if string.is_none() {
+			self.invalid_value(value, "expected a double quoted string");
+		}
+		string
+	}
+
+	/// Parses a string that must be one of the values accepted by `T`
+	fn keyword<T: FromStr>(&mut self, value: &JsAnyExpression, message: &str) -> Option<T> {
+		let keyword = self.string(value)?;
+		let parsed = T::from_str(&keyword).ok();
+		if parsed.is_none() {
+			self.invalid_value(value, message);
+		}
+		parsed
+	}
+
+	fn number<T: FromStr>(&mut self, value: &JsAnyExpression) -> Option<T> {
+		let number = match value {
+			JsAnyExpression::JsAnyLiteralExpression(
+				JsAnyLiteralExpression::JsNumberLiteralExpression(number),
+			) => number.value_token().ok(),
+			_ => None,
+		};
+
+		match number.and_then(|token| token.text_trimmed().parse().ok()) {
+			Some(number) => Some(number),
+			None => {
+				self.invalid_value(value, "expected a positive integer");
+				None
+			}
+		}
+	}
+
+	fn globs(&mut self, value: &JsAnyExpression) -> Option<GlobSet> {
+		let array = match value {
+			JsAnyExpression::JsArrayExpression(array) => array,
+			_ => {
+				self.invalid_value(value, "expected an array of globs");
+				return None;
+			}
+		};
+
+		let mut builder = GlobSetBuilder::new();
+		for element in array.elements().iter().flatten() {
+			let element = match JsAnyExpression::cast(element.syntax().clone()) {
+				Some(element) => element,
+				None => {
+					self.diagnostics.push(
+						Diagnostic::error(self.file_id, "Configuration", "invalid value")
+							.primary(element.syntax().text_trimmed_range(), "expected a glob"),
+					);
+					continue;
+				}
+			};
+
+			if let Some(glob) = self.string(&element) {
+				match Glob::new(&glob) {
+					Ok(glob) => {
+						builder.add(glob);
+					}
+					Err(err) => self.invalid_value(&element, &err.kind().to_string()),
+				}
+			}
+		}
+
+		match builder.build() {
+			Ok(globs) => Some(globs),
+			Err(err) => {
+				self.invalid_value(value, &err.kind().to_string());
+				None
+			}
+		}
+	}
+
+	fn unknown_key(&mut self, key: &str, range: TextRange, allowed_keys: &[&str]) {
+		self.diagnostics.push(
+			Diagnostic::error(
+				self.file_id,
+				"Configuration",
+				format!("unknown key \"{}\"", key),
+			)
+			.primary(range, "this key isn't supported")
+			.footer_note(format!(
+				"the supported keys are: {}",
+				allowed_keys.join(", ")
+			)),
+		);
+	}
+
+	fn invalid_value(&mut self, value: &JsAnyExpression, message: &str) {
+		self.diagnostics.push(
+			Diagnostic::error(self.file_id, "Configuration", "invalid value")
+				.primary(value.syntax().text_trimmed_range(), message),
+		);
+	}
+}
+
+/// Returns the value of a JSON string literal, or `None` if the text isn't in double quotes or
+/// uses an escape sequence that JSON doesn't support, for example `\x41`
+fn json_string(text: &str) -> Option<String> {
+	let content = text.strip_prefix('"')?.strip_suffix('"')?;
+	let mut value = String::with_capacity(content.len());
+	let mut chars = content.chars();
+
+	while let Some(char) = chars.next() {
+		if char != '\\' {
+			value.push(char);
+			continue;
+		}
+
+		let unescaped = match chars.next()? {
+			'"' => '"',
+			'\\' => '\\',
+			'/' => '/',
+			'b' => '\u{8}',
+			'f' => '\u{c}',
+			'n' => '\n',
+			'r' => '\r',
+			't' => '\t',
+			'u' => {
+				let code = unicode_escape(&mut chars)?;
+				// The characters outside of the basic plane are escaped as a surrogate pair
+				if (0xD800..0xDC00).contains(&code) {
+					if chars.next()? != '\\' || chars.next()? != 'u' {
+						return None;
+					}
+					let low = unicode_escape(&mut chars)?;
+					if !(0xDC00..0xE000).contains(&low) {
+						return None;
+					}
+					char::from_u32(0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00))?
+				} else {
+					char::from_u32(code)?
+				}
+			}
+			_ => return None,
+		};
+		value.push(unescaped);
+	}
+
+	Some(value)
+}
+
+/// Reads the four hexadecimal digits of a `\u` escape sequence
+fn unicode_escape(chars: &mut std::str::Chars) -> Option<u32> {
+	let digits: String = chars.take(4).collect();
+	if digits.len() != 4 {
+		return None;
+	}
+	u32::from_str_radix(&digits, 16).ok()
+}
+
+#[cfg(test)]
+mod test {
+	use super::{json_string, parse_configuration};
+	use rome_core::file_handlers::Language;
+	use rome_formatter::{IndentStyle, Semicolons, SourceType};
+	use std::path::PathBuf;
+
+	#[test]
+	fn parses_language_overrides() {
+		let source = r#"{
+			"formatter": { "indentStyle": "space", "indentSize": 4, "lineWidth": 100 },
+			"javascript": { "formatter": { "semicolons": "as-needed", "sourceType": "module" } },
+			"json": { "formatter": { "indentStyle": "tab" } }
+		}"#;
+		let configuration = parse_configuration(source, 0, PathBuf::new()).unwrap();
+
+		let js_options = configuration.format_options(Language::Js);
+		assert_eq!(js_options.indent_style, IndentStyle::Space(4));
+		assert_eq!(js_options.line_width, 100);
+		assert_eq!(js_options.semicolons, Semicolons::AsNeeded);
+		assert_eq!(js_options.source_type, SourceType::Module);
+
+		let json_options = configuration.format_options(Language::Json);
+		assert_eq!(json_options.indent_style, IndentStyle::Tab);
+		assert_eq!(json_options.line_width, 100);
+		assert_eq!(json_options.semicolons, Semicolons::Always);
+		assert_eq!(json_options.source_type, SourceType::Auto);
+	}
+
+	#[test]
+	fn indent_size_without_style_is_a_space_indent() {
+		let source = r#"{ "formatter": { "indentSize": 4 }, "json": { "formatter": { "indentStyle": "tab" } } }"#;
+		let configuration = parse_configuration(source, 0, PathBuf::new()).unwrap();
+
+		assert_eq!(
+			configuration.format_options(Language::Js).indent_style,
+			IndentStyle::Space(4)
+		);
+		assert_eq!(
+			configuration.format_options(Language::Json).indent_style,
+			IndentStyle::Tab
+		);
+
+		let source = r#"{ "formatter": { "indentStyle": "tab", "indentSize": 4 } }"#;
+		let diagnostics = parse_configuration(source, 0, PathBuf::new()).unwrap_err();
+		assert_eq!(diagnostics.len(), 1);
+	}
+
+	#[test]
+	fn reports_invalid_strings() {
+		let source = r#"{
+			// The ignored files
+			"formatter": { "ignore": ["\u0064ist/**", "a\"b", 'single', "\x41", "[",], },
+		}"#;
+		let diagnostics = parse_configuration(source, 0, PathBuf::new()).unwrap_err();
+
+		let invalid: Vec<_> = diagnostics
+			.iter()
+			.map(|diagnostic| {
+				let span = diagnostic.primary.as_ref().unwrap().span.range.clone();
+				&source[span]
+			})
+			.collect();
+		assert_eq!(invalid, ["'single'", r#""\x41""#, r#""[""#]);
+
+		assert_eq!(
+			json_string(r#""\u0064ist/\ud83d\ude00""#).unwrap(),
+			"dist/😀"
+		);
+	}
+
+	#[test]
+	fn reports_unknown_keys() {
+		let source = r#"{ "formatter": { "lineWidth": 100, "useTabs": true } }"#;
+		let diagnostics = parse_configuration(source, 0, PathBuf::new()).unwrap_err();
+
+		assert_eq!(diagnostics.len(), 1);
+		assert_eq!(diagnostics[0].title, "unknown key \"useTabs\"");
+		let span = diagnostics[0].primary.as_ref().unwrap().span.range.clone();
+		assert_eq!(&source[span], r#""useTabs""#);
+	}
+}
@@ -0,0 +1,251 @@
+//! Implementation of the `format` command over a list of files.
+use crate::configuration::Configuration;
+use rayon::prelude::*;
+use rome_core::{create_app, App};
+use rome_formatter::{format_source, FormatError, FormatOptions};
+use rome_path::RomePath;
+use rslint_errors::file::SimpleFile;
+use rslint_errors::termcolor::NoColor;
+use rslint_errors::{Diagnostic, Emitter};
+use similar::TextDiff;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+/// What the `format` command does with the files that aren't formatted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FormatMode {
+	/// Saves the formatted code to the file
+	Write,
+	/// Leaves the file untouched and reports the difference with the formatted code
+	Check,
+}
+
+/// Outcome of formatting a single file
+enum FormatStatus {
+	/// The file has been formatted and saved
+	Changed,
+	/// The file isn't formatted, with the unified diff between its content and the formatted code
+	Unformatted(String),
+	/// The file was already formatted
+	Unchanged,
+	/// The file can't be formatted by Rome, for example because its language isn't supported
+	Skipped,
+	/// Formatting the file failed, with the reason why
+	Failed(String),
+}
+
+/// Result of formatting a single file
+struct FileReport {
+	status: FormatStatus,
+	/// The warnings of the formatter, rendered with the code they point to
+	warnings: String,
+}
+
+impl From<FormatStatus> for FileReport {
+	fn from(status: FormatStatus) -> Self {
+		Self {
+			status,
+			warnings: String::new(),
+		}
+	}
+}
+
+/// Statistics about a run of the `format` command
+#[derive(Debug, Default)]
+pub(crate) struct FormatSummary {
+	/// Files that have been saved or, in [FormatMode::Check], that would be changed by the formatter
+	pub(crate) changed: usize,
+	pub(crate) unchanged: usize,
+	pub(crate) failed: usize,
+}
+
+impl FormatSummary {
+	/// Total number of files processed by the formatter
+	pub(crate) fn total(&self) -> usize {
+		self.changed + self.unchanged + self.failed
+	}
+}
+
+/// Formats `files` in parallel and, depending on `mode`, saves the files whose content changed
+/// or prints their diff on the standard output.
+///
+/// Files that can't be formatted and warnings are reported on the standard error. Diffs, warnings
+/// and errors are printed in the same order as `files`.
+pub(crate) fn format_files(
+	files: &[PathBuf],
+	configuration: &Configuration,
+	mode: FormatMode,
+) -> FormatSummary {
+	let reports: Vec<_> = files
+		.par_iter()
+		// Each worker has its own app, so that the handlers don't have to be shared across threads
+		.map_init(create_app, |app, path| {
+			format_file(app, path, configuration, mode)
+		})
+		.collect();
+
+	let mut summary = FormatSummary::default();
+
+	for (path, report) in files.iter().zip(reports) {
+		eprint!("{}", report.warnings);
+
+		match report.status {
+			FormatStatus::Changed => summary.changed += 1,
+			FormatStatus::Unformatted(diff) => {
+				summary.changed += 1;
+				print!("{}", diff);
+			}
+			FormatStatus::Unchanged => summary.unchanged += 1,
+			FormatStatus::Skipped => {}
+			FormatStatus::Failed(reason) => {
+				summary.failed += 1;
+				eprintln!("error: failed to format \"{}\": {}", path.display(), reason);
+			}
+		}
+	}
+
+	summary
+}
+
+/// Formats the code read from the standard input as if it was the content of the file at `path`.
+///
+/// The path is only used to know the language of the code, the file itself is never read nor
+/// written. The formatted code is printed on the standard output or, in [FormatMode::Check],
+/// the diff with the formatted code is printed instead.
+///
+/// Returns `true` if the code read from the standard input was already formatted.
+pub(crate) fn format_stdin(
+	path: &str,
+	configuration: &Configuration,
+	mode: FormatMode,
+) -> Result<bool, String> {
+	let mut content = String::new();
+	io::stdin()
+		.read_to_string(&mut content)
+		.map_err(|err| format!("cannot read the standard input: {}", err))?;
+
+	let app = create_app();
+	let rome_path = RomePath::new(path).deduce_handler(&app);
+	let options = match rome_path.get_handler() {
+		Some(handler) => configuration.format_options(handler.language()),
+		None => FormatOptions::default(),
+	};
+	let formatted = format_source(&rome_path, &content, options)
+		.map_err(|err| describe_error(path, &content, err))?;
+	eprint!(
+		"{}",
+		render_diagnostics(path, &content, formatted.diagnostics())
+	);
+	let is_formatted = formatted.code() == &content;
+
+	let output = match mode {
+		FormatMode::Write => formatted.code().clone(),
+		FormatMode::Check if is_formatted => String::new(),
+		FormatMode::Check => unified_diff(path, &content, formatted.code()),
+	};
+	io::stdout()
+		.write_all(output.as_bytes())
+		.map_err(|err| format!("cannot write to the standard output: {}", err))?;
+
+	Ok(is_formatted)
+}
+
+fn format_file(
+	app: &App,
+	path: &Path,
+	configuration: &Configuration,
+	mode: FormatMode,
+) -> FileReport {
+	let path_str = match path.to_str() {
+		Some(path_str) => path_str,
+		None => return FormatStatus::Failed(String::from("the path isn't valid UTF-8")).into(),
+	};
+
+	let mut rome_path = RomePath::new(path_str).deduce_handler(app);
+	let options = match rome_path.get_handler() {
+		Some(handler) if handler.capabilities().format => {
+			configuration.format_options(handler.language())
+		}
+		_ => return FormatStatus::Skipped.into(),
+	};
+
+	let content = match fs::read_to_string(path) {
+		Ok(content) => content,
+		Err(err) => return FormatStatus::Failed(err.to_string()).into(),
+	};
+
+	// A bug in the formatter must not abort the formatting of all the other files
+	let result = catch_unwind(AssertUnwindSafe(|| {
+		format_source(&rome_path, &content, options)
+	}));
+
+	let formatted = match result {
+		Ok(Ok(formatted)) => formatted,
+		Ok(Err(err)) => {
+			return FormatStatus::Failed(describe_error(path_str, &content, err)).into()
+		}
+		Err(_) => return FormatStatus::Failed(String::from("the formatter panicked")).into(),
+	};
+
+	let status = if formatted.code() == &content {
+		FormatStatus::Unchanged
+	} else {
+		match mode {
+			FormatMode::Write => match rome_path.save(formatted.code()) {
+				Ok(_) => FormatStatus::Changed,
+				Err(err) => FormatStatus::Failed(err.to_string()),
+			},
+			FormatMode::Check => {
+				FormatStatus::Unformatted(unified_diff(path_str, &content, formatted.code()))
+			}
+		}
+	};
+
+	FileReport {
+		status,
+		warnings: render_diagnostics(path_str, &content, formatted.diagnostics()),
+	}
+}
+
+/// Returns the message explaining why the file at `path` couldn't be formatted.
+///
+/// The syntax errors are rendered with a snippet of the code where they are.
+fn describe_error(path: &str, content: &str, error: FormatError) -> String {
+	let diagnostics = match &error {
+		FormatError::SyntaxError(diagnostics) => diagnostics,
+		_ => return error.to_string(),
+	};
+
+	format!(
+		"{}, use --format-with-errors to format it anyway\n{}",
+		error,
+		render_diagnostics(path, content, diagnostics)
+	)
+}
+
+/// Renders the diagnostics about the file at `path` with a snippet of the code they point to
+fn render_diagnostics(path: &str, content: &str, diagnostics: &[Diagnostic]) -> String {
+	if diagnostics.is_empty() {
+		return String::new();
+	}
+
+	let file = SimpleFile::new(path.to_string(), content.to_string());
+	let mut emitter = Emitter::new(&file);
+	let mut rendered = NoColor::new(Vec::new());
+	for diagnostic in diagnostics {
+		// Writing to a vector can't fail
+		let _ = emitter.emit_with_writer(diagnostic, &mut rendered);
+	}
+
+	String::from_utf8_lossy(&rendered.into_inner()).into_owned()
+}
+
+/// Returns the unified diff between the content of the file at `path` and its formatted code
+fn unified_diff(path: &str, content: &str, formatted: &str) -> String {
+	TextDiff::from_lines(content, formatted)
+		.unified_diff()
+		.header(path, path)
+		.to_string()
+}
@@ -1,8 +1,17 @@
 use clap::{crate_version, App, AppSettings, Arg};
-use rome_core::create_app;
-use rome_formatter::{format_file_and_save, FormatOptions, IndentStyle};
-use rome_path::RomePath;
-use std::{path::PathBuf, str::FromStr};
+use configuration::FormatterSettings;
+use format::FormatMode;
+use rome_formatter::{IndentStyle, LineEnding, QuoteStyle, Semicolons, SourceType, TrailingComma};
+use std::{env, path::PathBuf, str::FromStr};
+
+mod configuration;
+mod format;
+mod traversal;
+
+/// Exit code used when some files couldn't be formatted
+const EXIT_CODE_FAILURE: i32 = 1;
+/// Exit code used by `format --check` when some files aren't formatted
+const EXIT_CODE_UNFORMATTED: i32 = 2;
 
 /// Main function to run Rome CLI
 pub fn run_cli() {
@@ -12,13 +21,12 @@ pub fn run_cli() {
 		.setting(AppSettings::SubcommandRequiredElseHelp)
 		.subcommand(
 			App::new("format")
-				.about("Format a file")
+				.about("Format files")
 				.arg(
 					Arg::new("indent_style")
 						.long("indent-style")
 						.about("The style of indentation")
 						.value_name("tab|space")
-						.default_value("tab")
 						.validator(|value| IndentStyle::from_str(value).map(|_| ())),
 				)
 				.arg(
@@ -26,18 +34,90 @@ pub fn run_cli() {
 						.long("indent-size")
 						.about("The size of the indent.")
 						.value_name("NUMBER")
-						.default_value("2")
 						.validator(|value| {
 							value
 								.parse::<u8>()
 								.map_err(|_| "Invalid indent-size value. Try using a number")
 						}),
 				)
+				.arg(
+					Arg::new("line_width")
+						.long("line-width")
+						.about("The maximum width of a line")
+						.value_name("NUMBER")
+						.validator(|value| {
+							value
+								.parse::<u16>()
+								.map_err(|_| "Invalid line-width value. Try using a number")
+						}),
+				)
+				.arg(
+					Arg::new("line_ending")
+						.long("line-ending")
+						.about("The line ending of the formatted files. \"auto\" uses the line ending of each file")
+						.value_name("lf|crlf|cr|auto")
+						.validator(|value| LineEnding::from_str(value).map(|_| ())),
+				)
+				.arg(
+					Arg::new("quote_style")
+						.long("quote-style")
+						.about("The quotes used for the strings")
+						.value_name("double|single")
+						.validator(|value| QuoteStyle::from_str(value).map(|_| ())),
+				)
+				.arg(
+					Arg::new("semicolons")
+						.long("semicolons")
+						.about("Print semicolons at the end of all the statements, or only where they're needed")
+						.value_name("always|as-needed")
+						.validator(|value| Semicolons::from_str(value).map(|_| ())),
+				)
+				.arg(
+					Arg::new("trailing_comma")
+						.long("trailing-comma")
+						.about("Where a comma is printed after the last element of the lists broken over multiple lines")
+						.value_name("none|es5|all")
+						.validator(|value| TrailingComma::from_str(value).map(|_| ())),
+				)
+				.arg(
+					Arg::new("source_type")
+						.long("source-type")
+						.about("Parse the .js files as scripts or as ES modules. \"auto\" parses the files with import or export declarations as modules. The .mjs files are always modules and the .cjs files scripts")
+						.value_name("script|module|auto")
+						.validator(|value| SourceType::from_str(value).map(|_| ())),
+				)
+				.arg(
+					Arg::new("format_with_errors")
+						.long("format-with-errors")
+						.about("Format the files that contain syntax errors, the erroneous code is left untouched"),
+				)
+				.arg(
+					Arg::new("format_embedded_json")
+						.long("format-embedded-json")
+						.about("Format the content of the template literals tagged with `json` as JSON"),
+				)
+				.arg(
+					Arg::new("check")
+						.long("check")
+						.about("Print the difference with the formatted code instead of writing the files, and exit with a non-zero code if some files aren't formatted"),
+				)
+				.arg(
+					Arg::new("stdin_file_path")
+						.long("stdin-file-path")
+						.about("Format the code read from the standard input and print it on the standard output. The path is only used to know the language of the code, it doesn't need to exist")
+						.value_name("PATH")
+						.takes_value(true),
+				)
 				.arg(
 					Arg::new("input")
-						.about("File to format")
-						.required(true)
+						.about("Files, directories or glob patterns to format")
+						.required_unless_present("stdin_file_path")
+						.conflicts_with("stdin_file_path")
+						.multiple_values(true)
 						.validator(|value| {
+							if traversal::is_glob(value) {
+								return Ok(());
+							}
 							let path = PathBuf::from(&value);
 							if !path.exists() {
 								return Err(format!("The file \"{}\" doesn't exist.", value));
@@ -52,26 +132,112 @@ pub fn run_cli() {
 		Err(err) => err.exit(),
 	};
 
-	let app = create_app();
-
 	match subcommand_matches {
 		Some(("format", matches)) => {
-			let size = matches.value_of("indent_size");
-			let style = matches.value_of("indent_style");
-			let input = matches.value_of("input").unwrap();
-			let options: IndentStyle = style
-				.map(|s| match s {
-					"tab" => IndentStyle::Tab,
-					"space" => {
-						let size = size.unwrap_or("2");
-						IndentStyle::Space(size.parse::<u8>().unwrap_or(2))
+			let mut configuration = match env::current_dir()
+				.map_err(|err| configuration::ConfigurationError::Io(PathBuf::from("."), err))
+				.and_then(|directory| configuration::load_configuration(&directory))
+			{
+				Ok(configuration) => configuration,
+				Err(err) => {
+					err.report();
+					std::process::exit(EXIT_CODE_FAILURE);
+				}
+			};
+
+			// The options passed on the command line take precedence over the configuration file
+			configuration.override_formatter(&FormatterSettings {
+				indent_style: matches
+					.value_of("indent_style")
+					.and_then(|style| IndentStyle::from_str(style).ok()),
+				indent_size: matches
+					.value_of("indent_size")
+					.and_then(|size| size.parse().ok()),
+				line_width: matches
+					.value_of("line_width")
+					.and_then(|width| width.parse().ok()),
+				line_ending: matches
+					.value_of("line_ending")
+					.and_then(|ending| LineEnding::from_str(ending).ok()),
+				quote_style: matches
+					.value_of("quote_style")
+					.and_then(|style| QuoteStyle::from_str(style).ok()),
+				semicolons: matches
+					.value_of("semicolons")
+					.and_then(|semicolons| Semicolons::from_str(semicolons).ok()),
+				trailing_comma: matches
+					.value_of("trailing_comma")
+					.and_then(|trailing_comma| TrailingComma::from_str(trailing_comma).ok()),
+				source_type: matches
+					.value_of("source_type")
+					.and_then(|source_type| SourceType::from_str(source_type).ok()),
+				format_with_errors: if matches.is_present("format_with_errors") {
+					Some(true)
+				} else {
+					None
+				},
+				format_embedded_json: if matches.is_present("format_embedded_json") {
+					Some(true)
+				} else {
+					None
+				},
+			});
+
+			let mode = if matches.is_present("check") {
+				FormatMode::Check
+			} else {
+				FormatMode::Write
+			};
+
+			if let Some(path) = matches.value_of("stdin_file_path") {
+				match format::format_stdin(path, &configuration, mode) {
+					Ok(is_formatted) => {
+						if mode == FormatMode::Check && !is_formatted {
+							std::process::exit(EXIT_CODE_UNFORMATTED);
+						}
 					}
-					_ => IndentStyle::default(),
-				})
-				.unwrap_or_default();
+					Err(message) => {
+						eprintln!("error: failed to format \"{}\": {}", path, message);
+						std::process::exit(EXIT_CODE_FAILURE);
+					}
+				}
+				return;
+			}
+
+			let inputs: Vec<&str> = matches.values_of("input").unwrap().collect();
+			let mut files = match traversal::collect_files(&inputs) {
+				Ok(files) => files,
+				Err(message) => {
+					clap::Error::with_description(message, clap::ErrorKind::InvalidValue).exit()
+				}
+			};
+
+			files.retain(|file| !configuration.is_ignored(file));
+
+			let summary = format::format_files(&files, &configuration, mode);
+			match mode {
+				FormatMode::Write => println!(
+					"Formatted {} files: {} changed, {} unchanged, {} failed",
+					summary.total(),
+					summary.changed,
+					summary.unchanged,
+					summary.failed
+				),
+				FormatMode::Check => println!(
+					"Checked {} files: {} unformatted, {} formatted, {} failed",
+					summary.total(),
+					summary.changed,
+					summary.unchanged,
+					summary.failed
+				),
+			}
 
-			let mut file = RomePath::new(input).deduce_handler(&app);
-			format_file_and_save(&mut file, FormatOptions::new(options));
+			if summary.failed > 0 {
+				std::process::exit(EXIT_CODE_FAILURE);
+			}
+			if mode == FormatMode::Check && summary.changed > 0 {
+				std::process::exit(EXIT_CODE_UNFORMATTED);
+			}
 		}
 		// Thanks to the settings AppSettings::SubcommandRequiredElseHelp we should not be there
 		_ => clap::Error::with_description(
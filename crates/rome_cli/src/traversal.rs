@@ -0,0 +1,128 @@
+//! Resolves the inputs passed on the command line to the list of files Rome should process.
+//!
+//! An input can be:
+//! - a path to a file, which is returned as is
+//! - a path to a directory, which is walked recursively
+//! - a glob pattern (e.g. `packages/**/*.ts`), which is matched starting from the current directory
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directories that are never entered when walking a directory
+const IGNORED_DIRECTORIES: [&str; 1] = ["node_modules"];
+
+/// Returns `true` if the input should be interpreted as a glob pattern and not as a path
+pub(crate) fn is_glob(input: &str) -> bool {
+	input.contains(&['*', '?', '[', '{'][..])
+}
+
+/// Expands the inputs to the files they point to.
+///
+/// The returned list is sorted and doesn't contain duplicates, even if a file is matched by multiple inputs.
+pub(crate) fn collect_files(inputs: &[&str]) -> Result<Vec<PathBuf>, String> {
+	let mut files = BTreeSet::new();
+
+	for input in inputs {
+		if is_glob(input) {
+			let walker = globwalk::glob_builder(input)
+				.file_type(globwalk::FileType::FILE)
+				.build()
+				.map_err(|err| format!("Invalid glob pattern \"{}\": {}", input, err))?;
+
+			// Entries that can't be read (e.g. permission errors) are skipped with a warning
+			for entry in walker {
+				match entry {
+					Ok(entry) => {
+						files.insert(normalize(entry.path()));
+					}
+					Err(err) => eprintln!("warning: {}", err),
+				}
+			}
+		} else {
+			let path = Path::new(input);
+			if path.is_dir() {
+				walk_directory(path, &mut files);
+			} else {
+				files.insert(path.to_path_buf());
+			}
+		}
+	}
+
+	Ok(files.into_iter().collect())
+}
+
+/// Recursively collects all the files inside `directory`, skipping hidden and ignored directories.
+///
+/// Symbolic links to files are collected, symbolic links to directories aren't followed, like
+/// with glob patterns, so that a link to a parent directory doesn't loop forever.
+/// Directories and entries that can't be read are skipped with a warning.
+fn walk_directory(directory: &Path, files: &mut BTreeSet<PathBuf>) {
+	let entries = match fs::read_dir(directory) {
+		Ok(entries) => entries,
+		Err(err) => {
+			eprintln!(
+				"warning: cannot read the directory \"{}\": {}",
+				directory.display(),
+				err
+			);
+			return;
+		}
+	};
+
+	for entry in entries {
+		let entry = match entry {
+			Ok(entry) => entry,
+			Err(err) => {
+				eprintln!(
+					"warning: cannot read an entry of \"{}\": {}",
+					directory.display(),
+					err
+				);
+				continue;
+			}
+		};
+
+		let path = entry.path();
+		let file_type = match entry.file_type() {
+			Ok(file_type) => file_type,
+			Err(err) => {
+				eprintln!("warning: cannot read \"{}\": {}", path.display(), err);
+				continue;
+			}
+		};
+
+		if file_type.is_symlink() {
+			if path.is_file() {
+				files.insert(path);
+			}
+		} else if file_type.is_dir() {
+			let name = entry.file_name();
+			let name = name.to_string_lossy();
+			if name.starts_with('.') || IGNORED_DIRECTORIES.contains(&name.as_ref()) {
+				continue;
+			}
+			walk_directory(&path, files);
+		} else if file_type.is_file() {
+			files.insert(path);
+		}
+	}
+}
+
+/// Removes the leading `./` that the glob walker adds to the matched paths
+fn normalize(path: &Path) -> PathBuf {
+	path.strip_prefix(".").unwrap_or(path).to_path_buf()
+}
+
+#[cfg(test)]
+mod test {
+	use super::is_glob;
+
+	#[test]
+	fn detects_glob_patterns() {
+		assert!(is_glob("src/**/*.js"));
+		assert!(is_glob("file?.js"));
+		assert!(is_glob("{src,test}/index.ts"));
+		assert!(!is_glob("src/index.js"));
+		assert!(!is_glob("src"));
+	}
+}
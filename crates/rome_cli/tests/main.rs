@@ -1,4 +1,9 @@
-use std::{env, path::PathBuf, process::Command};
+use std::{
+	env, fs,
+	io::Write,
+	path::PathBuf,
+	process::{Command, Stdio},
+};
 
 fn cargo_bin(name: &str) -> PathBuf {
 	env::current_exe()
@@ -34,3 +39,244 @@ fn test_format_cli() {
 "#
 	);
 }
+
+/// Creates an empty directory inside the temporary directory of the system
+fn create_temp_dir(name: &str) -> PathBuf {
+	let dir = env::temp_dir().join(format!("rome_cli_{}", name));
+	if dir.exists() {
+		fs::remove_dir_all(&dir).expect("cannot clean the temporary directory");
+	}
+	fs::create_dir_all(&dir).expect("cannot create the temporary directory");
+	dir
+}
+
+#[test]
+fn test_format_directory() {
+	let dir = create_temp_dir("format_directory");
+	fs::create_dir_all(dir.join("nested")).unwrap();
+	fs::create_dir_all(dir.join("node_modules")).unwrap();
+	fs::write(dir.join("a.js"), "let a =     1").unwrap();
+	fs::write(dir.join("nested/b.js"), "let b = 2;\n").unwrap();
+	fs::write(dir.join("nested/c.json"), "{ \"c\":   3 }").unwrap();
+	fs::write(dir.join("nested/d.txt"), "not formatted").unwrap();
+	fs::write(dir.join("node_modules/e.js"), "let e =     5").unwrap();
+
+	let res = Command::new(cargo_bin("cli"))
+		.arg("format")
+		.arg(&dir)
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert_eq!(
+		output,
+		"Formatted 3 files: 2 changed, 1 unchanged, 0 failed\n"
+	);
+
+	assert_eq!(
+		fs::read_to_string(dir.join("a.js")).unwrap(),
+		"let a = 1;\n"
+	);
+	assert_eq!(
+		fs::read_to_string(dir.join("nested/c.json")).unwrap(),
+		"{\"c\": 3}\n"
+	);
+	assert_eq!(
+		fs::read_to_string(dir.join("nested/d.txt")).unwrap(),
+		"not formatted"
+	);
+	assert_eq!(
+		fs::read_to_string(dir.join("node_modules/e.js")).unwrap(),
+		"let e =     5"
+	);
+}
+
+#[test]
+fn test_format_check() {
+	let dir = create_temp_dir("format_check");
+	fs::write(dir.join("a.js"), "let a =     1;\n").unwrap();
+	fs::write(dir.join("b.js"), "let b = 2;\n").unwrap();
+
+	let res = Command::new(cargo_bin("cli"))
+		.args(&["format", "--check"])
+		.arg(&dir)
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(res.status.code(), Some(2));
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	let path = dir.join("a.js").display().to_string();
+	assert_eq!(
+		output,
+		format!(
+			"--- {}\n+++ {}\n@@ -1 +1 @@\n-let a =     1;\n+let a = 1;\nChecked 2 files: 1 unformatted, 1 formatted, 0 failed\n",
+			path, path
+		)
+	);
+
+	// The files are never written
+	assert_eq!(
+		fs::read_to_string(dir.join("a.js")).unwrap(),
+		"let a =     1;\n"
+	);
+}
+
+#[test]
+fn test_format_stdin() {
+	let mut child = Command::new(cargo_bin("cli"))
+		.args(&["format", "--stdin-file-path", "virtual/file.js"])
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.spawn()
+		.expect("fail to run cli format");
+
+	child
+		.stdin
+		.take()
+		.unwrap()
+		.write_all(b"let a =     1")
+		.unwrap();
+	let res = child.wait_with_output().unwrap();
+
+	assert!(res.status.success(), "cli format command failed");
+	let output = String::from_utf8(res.stdout).expect("cannot read stdout, not utf8 compliant");
+	assert_eq!(output, "let a = 1;\n");
+}
+
+#[test]
+fn test_format_with_configuration() {
+	let dir = create_temp_dir("format_with_configuration");
+	fs::create_dir_all(dir.join("ignored")).unwrap();
+	fs::write(
+		dir.join("rome.json"),
+		r#"{
+	"formatter": { "indentStyle": "space", "indentSize": 4, "ignore": ["ignored/**"] },
+	"json": { "formatter": { "indentStyle": "tab" } }
+}"#,
+	)
+	.unwrap();
+	fs::write(dir.join("a.js"), "function f() { return 1 }").unwrap();
+	fs::write(dir.join("ignored/b.js"), "let b =     2").unwrap();
+
+	let res = Command::new(cargo_bin("cli"))
+		.args(&["format", "."])
+		.current_dir(&dir)
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	assert_eq!(
+		fs::read_to_string(dir.join("a.js")).unwrap(),
+		"function f() {\n    return 1;\n}\n"
+	);
+	assert_eq!(
+		fs::read_to_string(dir.join("ignored/b.js")).unwrap(),
+		"let b =     2"
+	);
+}
+
+#[test]
+fn test_format_with_invalid_configuration() {
+	let dir = create_temp_dir("format_with_invalid_configuration");
+	fs::write(
+		dir.join("rome.json"),
+		r#"{ "formatter": { "tabs": true } }"#,
+	)
+	.unwrap();
+	fs::write(dir.join("a.js"), "let a =     1").unwrap();
+
+	let res = Command::new(cargo_bin("cli"))
+		.args(&["format", "."])
+		.current_dir(&dir)
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(res.status.code(), Some(1));
+	let error = String::from_utf8(res.stderr).expect("cannot read stderr, not utf8 compliant");
+	assert!(error.contains("unknown key \"tabs\""), "{}", error);
+	assert_eq!(
+		fs::read_to_string(dir.join("a.js")).unwrap(),
+		"let a =     1"
+	);
+}
+
+#[test]
+fn test_format_options() {
+	let dir = create_temp_dir("format_options");
+	fs::write(dir.join("a.js"), "let a = \"a\";\r\nlet b =   \"b\";\r\n").unwrap();
+	fs::write(dir.join("b.js"), "let b = \"b\";\n").unwrap();
+
+	let res = Command::new(cargo_bin("cli"))
+		.args(&["format", "--line-ending", "auto", "--quote-style", "single"])
+		.arg(&dir)
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	assert_eq!(
+		fs::read_to_string(dir.join("a.js")).unwrap(),
+		"let a = 'a';\r\nlet b = 'b';\r\n"
+	);
+	assert_eq!(
+		fs::read_to_string(dir.join("b.js")).unwrap(),
+		"let b = 'b';\n"
+	);
+}
+
+#[test]
+fn test_format_syntax_errors() {
+	let dir = create_temp_dir("format_syntax_errors");
+	fs::write(dir.join("a.js"), "let a =     1;\nif test {}\n").unwrap();
+
+	let res = Command::new(cargo_bin("cli"))
+		.arg("format")
+		.arg(&dir)
+		.output()
+		.expect("fail to run cli format");
+
+	assert_eq!(res.status.code(), Some(1));
+	let error = String::from_utf8(res.stderr).expect("cannot read stderr, not utf8 compliant");
+	assert!(error.contains("syntax error"), "{}", error);
+	assert!(error.contains("if test {}"), "{}", error);
+	assert_eq!(
+		fs::read_to_string(dir.join("a.js")).unwrap(),
+		"let a =     1;\nif test {}\n"
+	);
+
+	let res = Command::new(cargo_bin("cli"))
+		.args(&["format", "--format-with-errors"])
+		.arg(&dir)
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	assert_eq!(
+		fs::read_to_string(dir.join("a.js")).unwrap(),
+		"let a = 1;\nif test {}\n"
+	);
+}
+
+#[test]
+fn test_format_unsupported_nodes() {
+	let dir = create_temp_dir("format_unsupported_nodes");
+	fs::write(dir.join("a.js"), "let a =     1;\nlet b = /ab+c/i;\n").unwrap();
+
+	let res = Command::new(cargo_bin("cli"))
+		.arg("format")
+		.arg(&dir)
+		.output()
+		.expect("fail to run cli format");
+
+	assert!(res.status.success(), "cli format command failed");
+	let warning = String::from_utf8(res.stderr).expect("cannot read stderr, not utf8 compliant");
+	assert!(
+		warning.contains("JS_REGEX_LITERAL_EXPRESSION"),
+		"{}",
+		warning
+	);
+	assert_eq!(
+		fs::read_to_string(dir.join("a.js")).unwrap(),
+		"let a = 1;\nlet b = /ab+c/i;\n"
+	);
+}
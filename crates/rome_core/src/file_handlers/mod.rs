@@ -1,5 +1,6 @@
 pub mod javascript;
 pub mod json;
+pub mod typescript;
 pub mod unknown;
 
 pub enum Language {
@@ -0,0 +1,25 @@
+use super::{ExtensionHandler, Mime};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TsFileHandler {}
+
+impl ExtensionHandler for TsFileHandler {
+	fn capabilities(&self) -> super::Capabilities {
+		super::Capabilities {
+			format: true,
+			lint: true,
+		}
+	}
+
+	fn language(&self) -> super::Language {
+		super::Language::Ts
+	}
+
+	fn mime(&self) -> super::Mime {
+		Mime::Javascript
+	}
+
+	fn may_use_tabs(&self) -> bool {
+		true
+	}
+}
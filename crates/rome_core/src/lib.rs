@@ -1,4 +1,6 @@
-use crate::file_handlers::{javascript::JsFileHandler, unknown::UnknownFileHandler};
+use crate::file_handlers::{
+	javascript::JsFileHandler, typescript::TsFileHandler, unknown::UnknownFileHandler,
+};
 use file_handlers::{json::JsonFileHandler, ExtensionHandler};
 use std::collections::HashMap;
 
@@ -16,7 +18,9 @@ impl Default for App {
 	fn default() -> Self {
 		let mut map: Handlers = HashMap::new();
 		map.insert("js", Box::new(JsFileHandler {}));
-		map.insert("ts", Box::new(JsFileHandler {}));
+		map.insert("mjs", Box::new(JsFileHandler {}));
+		map.insert("cjs", Box::new(JsFileHandler {}));
+		map.insert("ts", Box::new(TsFileHandler {}));
 		map.insert("json", Box::new(JsonFileHandler {}));
 		Self {
 			handlers: map,
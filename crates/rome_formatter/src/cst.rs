@@ -182,10 +182,7 @@ impl ToFormatElement for SyntaxNode {
 				.unwrap()
 				.to_format_element(formatter),
 
-			_ => todo!(
-				"Implement formatting for the {:?} syntax kind.",
-				self.kind()
-			),
+			_ => Ok(formatter.format_unsupported(self)),
 		}
 	}
 }
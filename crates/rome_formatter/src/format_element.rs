@@ -1,5 +1,6 @@
 use crate::format_elements;
 use crate::intersperse::Intersperse;
+use rome_rowan::TextSize;
 use std::ops::Deref;
 
 type Content = Box<FormatElement>;
@@ -79,6 +80,29 @@ pub const fn hard_line_break() -> FormatElement {
 	FormatElement::Line(Line::new(LineMode::Hard))
 }
 
+/// A forced line break followed by an empty line. Like a [hard_line_break], it forces any enclosing
+/// [Group] to be printed over multiple lines. Consecutive empty lines are printed as a single one,
+/// and no empty line is printed at the start of the document.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_element, format_elements, token, FormatOptions, empty_line};
+///
+/// let elements = format_elements![
+///   token("a,"),
+///   empty_line(),
+///   empty_line(),
+///   token("b"),
+/// ];
+///
+/// assert_eq!("a,\n\nb", format_element(&elements, FormatOptions::default()).code());
+/// ```
+#[inline]
+pub const fn empty_line() -> FormatElement {
+	FormatElement::Line(Line::new(LineMode::Empty))
+}
+
 /// A line break if the enclosing [Group] doesn't fit on a single line, a space otherwise.
 ///
 /// ## Examples
@@ -172,6 +196,84 @@ pub const fn space_token() -> FormatElement {
 	FormatElement::Space
 }
 
+/// Marks the position in the source code of the content that follows it. The printer maps the
+/// position to where the content is printed, which allows to create the source map of the
+/// formatted code, see [crate::Formatted::source_map].
+///
+/// The marker itself doesn't print anything.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_element, format_elements, source_marker, token, FormatOptions, SourceMapping};
+///
+/// let elements = format_elements![token("a"), source_marker(3.into()), token("b")];
+/// let formatted = format_element(&elements, FormatOptions::default());
+///
+/// assert_eq!("ab", formatted.code());
+/// assert_eq!(
+///     &[SourceMapping { source: 3.into(), generated: 1.into() }],
+///     formatted.mappings()
+/// );
+/// ```
+#[inline]
+pub const fn source_marker(position: TextSize) -> FormatElement {
+	FormatElement::SourceMarker(position)
+}
+
+/// Prints the content at the end of the line, before the next line break, even if other content
+/// follows it on the line. It's used for trailing line comments, which must stay at the end of
+/// their line. A [Group] that prints content after a line suffix doesn't fit on a single line.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_element, format_elements, hard_line_break, line_suffix, space_token, token, FormatOptions};
+///
+/// let elements = format_elements![
+///   token("a"),
+///   line_suffix(format_elements![space_token(), token("// comment")]),
+///   token(";"),
+///   hard_line_break(),
+/// ];
+///
+/// assert_eq!("a; // comment\n", format_element(&elements, FormatOptions::default()).code());
+/// ```
+#[inline]
+pub fn line_suffix<T: Into<FormatElement>>(content: T) -> FormatElement {
+	let content = content.into();
+
+	if content.is_empty() {
+		content
+	} else {
+		FormatElement::from(LineSuffix::new(content))
+	}
+}
+
+/// Prints the pending [line_suffix]es followed by a line break, if there are any. It prints nothing
+/// otherwise. For example, a line comment in a template literal's `${}` must be printed before the
+/// closing `}`, rather than in the string.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{format_element, format_elements, line_suffix, line_suffix_boundary, token, FormatOptions};
+///
+/// let elements = format_elements![
+///   token("`${a"),
+///   line_suffix(token(" // comment")),
+///   line_suffix_boundary(),
+///   token("}`"),
+///   line_suffix_boundary(),
+/// ];
+///
+/// assert_eq!("`${a // comment\n}`", format_element(&elements, FormatOptions::default()).code());
+/// ```
+#[inline]
+pub const fn line_suffix_boundary() -> FormatElement {
+	FormatElement::LineSuffixBoundary
+}
+
 /// Concatenates the content of multiple [FormatToken]s.
 ///
 /// ## Examples
@@ -576,6 +678,72 @@ where
 	}
 }
 
+/// Fills each line with as many of the elements as fit, for example the numbers of an array.
+///
+/// The `separator` between two elements is printed as if it were in a [Group] that fits on a
+/// single line if the element that follows it fits on the rest of the line. Otherwise, the
+/// separator is printed broken: a [soft_line_break_or_space] moves the element to the next line.
+/// The elements themselves are printed as usual, their groups break if they don't fit.
+///
+/// ## Examples
+///
+/// ```
+/// use rome_formatter::{fill_elements, format_element, token, soft_line_break_or_space, FormatOptions};
+///
+/// let elements = fill_elements(
+///   soft_line_break_or_space(),
+///   vec![token("1,"), token("2,"), token("3,"), token("4,"), token("5,"), token("6")],
+/// );
+///
+/// let options = FormatOptions { line_width: 8, ..FormatOptions::default() };
+/// assert_eq!("1, 2, 3,\n4, 5, 6", format_element(&elements, options).code());
+/// ```
+pub fn fill_elements<TSep, I>(separator: TSep, elements: I) -> FormatElement
+where
+	TSep: Into<FormatElement>,
+	I: IntoIterator<Item = FormatElement>,
+{
+	let mut list: Vec<_> = elements.into_iter().filter(|e| !e.is_empty()).collect();
+
+	match list.len() {
+		0 => empty_element(),
+		1 => list.pop().unwrap(),
+		_ => FormatElement::from(Fill::new(list, separator.into())),
+	}
+}
+
+/// Prints the first of the `variants` that fits, from the most flat to the most expanded layout.
+///
+/// The first variant is printed if it fits on the line with all its groups printed flat.
+/// Otherwise, the printer picks the first variant whose first line fits, with its groups printed
+/// as usual, or the last variant if none does. The last variant is never measured.
+///
+/// ## Examples
+///
+/// Hugs the last argument of a call if the call fits on the line up to its body
+///
+/// ```
+/// use rome_formatter::{best_fitting, block_indent, format_element, format_elements, space_token, token, FormatOptions};
+///
+/// let callback = format_elements![token("() => {"), block_indent(token("body();")), token("}")];
+///
+/// let call = best_fitting(vec![
+///   format_elements![token("foo(a, () => {"), space_token(), token("body();"), space_token(), token("})")],
+///   format_elements![token("foo(a, "), callback.clone(), token(")")],
+///   format_elements![token("foo("), block_indent(format_elements![token("a,"), callback]), token(")")],
+/// ]);
+///
+/// let options = FormatOptions { line_width: 16, ..FormatOptions::default() };
+/// assert_eq!("foo(a, () => {\n\tbody();\n})", format_element(&call, options).code());
+/// ```
+pub fn best_fitting(mut variants: Vec<FormatElement>) -> FormatElement {
+	match variants.len() {
+		0 => empty_element(),
+		1 => variants.pop().unwrap(),
+		_ => FormatElement::from(BestFitting::new(variants)),
+	}
+}
+
 /// Language agnostic IR for formatting source code.
 ///
 /// Use the helper functions like [space], [soft_line_break] etc. defined in this file to create elements.
@@ -606,8 +774,23 @@ pub enum FormatElement {
 	/// Concatenates multiple elements together. See [concat_elements] and [join_elements] for examples.
 	List(List),
 
+	/// Fills each line with as many elements as fit, see [fill_elements] for documentation and examples.
+	Fill(Fill),
+
+	/// Prints the first of multiple variants that fits, see [best_fitting] for documentation and examples.
+	BestFitting(BestFitting),
+
+	/// Content printed at the end of the line, see [line_suffix] for documentation and examples.
+	LineSuffix(LineSuffix),
+
+	/// Flushes the pending line suffixes, see [line_suffix_boundary] for documentation and examples.
+	LineSuffixBoundary,
+
 	/// A token that should be printed as is, see [token] for documentation and examples.
 	Token(Token),
+
+	/// The position in the source code of the content that follows, see [source_marker] for documentation.
+	SourceMarker(TextSize),
 }
 
 /// Inserts a new line
@@ -630,6 +813,8 @@ pub enum LineMode {
 	Soft,
 	/// See [hard_line_break] for documentation.
 	Hard,
+	/// See [empty_line] for documentation.
+	Empty,
 }
 
 /// Increases the indention by one; see [indented_with_soft_break] and [indented_with_hard_break].
@@ -646,6 +831,20 @@ impl Indent {
 	}
 }
 
+/// Content printed at the end of the line; see [line_suffix].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LineSuffix {
+	pub(crate) content: Content,
+}
+
+impl LineSuffix {
+	pub fn new(content: FormatElement) -> Self {
+		Self {
+			content: Box::new(content),
+		}
+	}
+}
+
 /// A token used to gather a list of elements; see [concat_elements] and [join_elements].
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct List {
@@ -666,6 +865,39 @@ impl Deref for List {
 	}
 }
 
+/// The elements of a [fill_elements] and the separator printed between them
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Fill {
+	pub(crate) list: List,
+	pub(crate) separator: Content,
+}
+
+impl Fill {
+	pub fn new(list: Vec<FormatElement>, separator: FormatElement) -> Self {
+		Self {
+			list: List::new(list),
+			separator: Box::new(separator),
+		}
+	}
+}
+
+/// The variants of a [best_fitting], from the most flat to the most expanded layout
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BestFitting {
+	pub(crate) variants: Vec<FormatElement>,
+}
+
+impl BestFitting {
+	pub fn new(variants: Vec<FormatElement>) -> Self {
+		debug_assert!(
+			variants.len() >= 2,
+			"A best fitting element requires at least two variants"
+		);
+
+		Self { variants }
+	}
+}
+
 /// Group is a special token that controls how the child tokens are printed.
 ///
 /// The printer first tries to print all tokens in the group onto a single line (ignoring soft line wraps)
@@ -745,27 +977,48 @@ impl FormatElement {
 			FormatElement::Group(g) => g.content.trim_start(),
 			FormatElement::ConditionalGroupContent(g) => g.content.trim_start(),
 			FormatElement::List(list) => {
-				let mut content: Vec<_> = list
-					.iter()
-					.skip_while(|e| match e {
-						FormatElement::Empty => true,
-						FormatElement::Space => true,
-						FormatElement::Line(_) => true,
-						FormatElement::Indent(_) => true,
+				// The source markers are kept so that the trimmed content stays mapped to the source
+				let mut content = Vec::with_capacity(list.len());
+				let mut elements = list.iter();
+
+				for element in &mut elements {
+					match element {
+						FormatElement::SourceMarker(_) => content.push(element.clone()),
+						FormatElement::Token(t) if t.trim_start().is_empty() => {}
 						FormatElement::Token(t) => {
-							let s = t.trim_start();
-							s.is_empty()
+							content.push(token(t.trim_start()));
+							break;
 						}
-						_ => false,
-					})
-					.map(Clone::clone)
-					.collect();
-				if let Some(FormatElement::Token(s)) = content.get_mut(0) {
-					s.0 = s.trim_start().to_string()
+						FormatElement::Empty
+						| FormatElement::Space
+						| FormatElement::Line(_)
+						| FormatElement::Indent(_) => {}
+						_ => {
+							content.push(element.clone());
+							break;
+						}
+					}
 				}
+
+				content.extend(elements.cloned());
 				FormatElement::List(List::new(content))
 			}
+			FormatElement::Fill(fill) => {
+				let mut list = fill.list.content.clone();
+				list[0] = list[0].trim_start();
+				FormatElement::from(Fill::new(list, (*fill.separator).clone()))
+			}
+			FormatElement::BestFitting(best_fitting) => FormatElement::from(BestFitting::new(
+				best_fitting
+					.variants
+					.iter()
+					.map(FormatElement::trim_start)
+					.collect(),
+			)),
 			FormatElement::Token(s) => token(s.trim_start()),
+			FormatElement::SourceMarker(_)
+			| FormatElement::LineSuffix(_)
+			| FormatElement::LineSuffixBoundary => self.clone(),
 		}
 	}
 
@@ -781,35 +1034,49 @@ impl FormatElement {
 			FormatElement::Group(g) => g.content.trim_end(),
 			FormatElement::ConditionalGroupContent(g) => g.content.trim_end(),
 			FormatElement::List(list) => {
-				let idx_first_non_empty = list.iter().rev().position(|e| match e {
-					FormatElement::Empty => false,
-					FormatElement::Space => false,
-					FormatElement::Line(_) => false,
-					FormatElement::Indent(_) => false,
-					FormatElement::Token(t) => {
-						let s = t.trim_end();
-						!s.is_empty()
-					}
-					_ => true,
-				});
-
-				match idx_first_non_empty {
-					Some(idx_first_non_empty) => {
-						let idx_first_non_empty = list.len() - idx_first_non_empty;
-						let mut content: Vec<_> = list
-							.iter()
-							.take(idx_first_non_empty)
-							.map(Clone::clone)
-							.collect();
-						if let Some(FormatElement::Token(s)) = content.last_mut() {
-							s.0 = s.trim_end().to_string()
+				let mut content = Vec::with_capacity(list.len());
+				let mut elements = list.iter().rev();
+
+				for element in &mut elements {
+					match element {
+						FormatElement::SourceMarker(_) => content.push(element.clone()),
+						FormatElement::Token(t) if t.trim_end().is_empty() => {}
+						FormatElement::Token(t) => {
+							content.push(token(t.trim_end()));
+							break;
+						}
+						FormatElement::Empty
+						| FormatElement::Space
+						| FormatElement::Line(_)
+						| FormatElement::Indent(_) => {}
+						_ => {
+							content.push(element.clone());
+							break;
 						}
-						FormatElement::List(List::new(content))
 					}
-					None => FormatElement::List(List::new(vec![])),
 				}
+
+				content.extend(elements.cloned());
+				content.reverse();
+				FormatElement::List(List::new(content))
 			}
+			FormatElement::Fill(fill) => {
+				let mut list = fill.list.content.clone();
+				let last = list.len() - 1;
+				list[last] = list[last].trim_end();
+				FormatElement::from(Fill::new(list, (*fill.separator).clone()))
+			}
+			FormatElement::BestFitting(best_fitting) => FormatElement::from(BestFitting::new(
+				best_fitting
+					.variants
+					.iter()
+					.map(FormatElement::trim_end)
+					.collect(),
+			)),
 			FormatElement::Token(s) => token(s.trim_end()),
+			FormatElement::SourceMarker(_)
+			| FormatElement::LineSuffix(_)
+			| FormatElement::LineSuffixBoundary => self.clone(),
 		}
 	}
 }
@@ -832,6 +1099,24 @@ impl From<ConditionalGroupContent> for FormatElement {
 	}
 }
 
+impl From<Fill> for FormatElement {
+	fn from(fill: Fill) -> Self {
+		FormatElement::Fill(fill)
+	}
+}
+
+impl From<BestFitting> for FormatElement {
+	fn from(best_fitting: BestFitting) -> Self {
+		FormatElement::BestFitting(best_fitting)
+	}
+}
+
+impl From<LineSuffix> for FormatElement {
+	fn from(line_suffix: LineSuffix) -> Self {
+		FormatElement::LineSuffix(line_suffix)
+	}
+}
+
 impl From<Line> for FormatElement {
 	fn from(token: Line) -> Self {
 		FormatElement::Line(token)
@@ -848,7 +1133,7 @@ impl From<Indent> for FormatElement {
 mod tests {
 
 	use crate::format_element::{empty_element, join_elements, List};
-	use crate::{concat_elements, space_token, token, FormatElement};
+	use crate::{concat_elements, source_marker, space_token, token, FormatElement};
 
 	#[test]
 	fn concat_elements_returns_a_list_token_containing_the_passed_in_elements() {
@@ -977,4 +1262,26 @@ mod tests {
 		matches!(f.trim_start(), FormatElement::Empty);
 		matches!(f.trim_end(), FormatElement::Empty);
 	}
+
+	#[test]
+	fn format_element_trim_keeps_source_markers() {
+		let element = concat_elements(vec![
+			source_marker(0.into()),
+			token("  "),
+			source_marker(2.into()),
+			token(" a "),
+			space_token(),
+			source_marker(5.into()),
+		]);
+
+		assert_eq!(
+			element.trim_start().trim_end(),
+			concat_elements(vec![
+				source_marker(0.into()),
+				source_marker(2.into()),
+				token("a"),
+				source_marker(5.into()),
+			])
+		);
+	}
 }
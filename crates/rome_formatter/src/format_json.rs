@@ -4,82 +4,122 @@ use crate::{
 	space_token, token,
 };
 use rslint_parser::ast::{
-	JsAnyObjectMember, JsArrayExpression, JsBooleanLiteralExpression, JsLiteralMemberName,
-	JsNullLiteralExpression, JsNumberLiteralExpression, JsObjectExpression,
-	JsParenthesizedExpression, JsPropertyObjectMember, JsStringLiteralExpression,
-	JsUnaryExpression,
+	JsAnyExpression, JsAnyObjectMember, JsAnyStatement, JsArrayExpression,
+	JsBooleanLiteralExpression, JsLiteralMemberName, JsNullLiteralExpression,
+	JsNumberLiteralExpression, JsObjectExpression, JsPropertyObjectMember,
+	JsStringLiteralExpression, JsUnaryExpression,
 };
 use rslint_parser::{parse_text, AstNode, SyntaxKind, SyntaxNode, SyntaxNodeExt, SyntaxToken};
 
-fn tokenize_token(syntax_token: SyntaxToken) -> FormatElement {
-	match syntax_token.kind() {
+/// Returns `None` if the token isn't valid in a JSON document
+fn tokenize_token(syntax_token: SyntaxToken) -> Option<FormatElement> {
+	let element = match syntax_token.kind() {
 		SyntaxKind::NULL_KW => token("null"),
 		SyntaxKind::TRUE_KW => token("true"),
 		SyntaxKind::FALSE_KW => token("false"),
-		SyntaxKind::JS_STRING_LITERAL => token(syntax_token.text_trimmed()),
-		SyntaxKind::JS_NUMBER_LITERAL => token(syntax_token.text_trimmed()),
+		SyntaxKind::JS_STRING_LITERAL if syntax_token.text_trimmed().starts_with('"') => {
+			token(syntax_token.text_trimmed())
+		}
+		SyntaxKind::JS_NUMBER_LITERAL if is_json_number(syntax_token.text_trimmed()) => {
+			token(syntax_token.text_trimmed())
+		}
 		SyntaxKind::MINUS => token("-"),
-		_ => panic!("Unsupported JSON token {:?}", syntax_token),
+		_ => return None,
+	};
+
+	Some(element)
+}
+
+/// Returns `true` if `text` is a number as JSON defines it, without its sign. JSON has no hex,
+/// octal or binary numbers, no BigInts, no separators, no leading zeros, and no numbers that
+/// start or end with a `.`
+fn is_json_number(text: &str) -> bool {
+	fn skip_digits(text: &str) -> (usize, &str) {
+		let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+		(digits, &text[digits..])
 	}
+
+	let rest = match text.strip_prefix('0') {
+		Some(rest) => rest,
+		None => match skip_digits(text) {
+			(0, _) => return false,
+			(_, rest) => rest,
+		},
+	};
+
+	let rest = match rest.strip_prefix('.') {
+		Some(fraction) => match skip_digits(fraction) {
+			(0, _) => return false,
+			(_, rest) => rest,
+		},
+		None => rest,
+	};
+
+	let rest = match rest.strip_prefix(&['e', 'E'][..]) {
+		Some(exponent) => {
+			let exponent = exponent.strip_prefix(&['+', '-'][..]).unwrap_or(exponent);
+			match skip_digits(exponent) {
+				(0, _) => return false,
+				(_, rest) => rest,
+			}
+		}
+		None => rest,
+	};
+
+	rest.is_empty()
 }
 
-fn tokenize_node(node: SyntaxNode) -> FormatElement {
-	match node.kind() {
+/// Returns `None` if the node, or one of its children, isn't valid in a JSON document
+fn tokenize_node(node: SyntaxNode) -> Option<FormatElement> {
+	let element = match node.kind() {
 		SyntaxKind::JS_LITERAL_MEMBER_NAME => {
-			tokenize_token(node.to::<JsLiteralMemberName>().value().unwrap())
-		}
-		SyntaxKind::JS_STRING_LITERAL_EXPRESSION => tokenize_token(
-			node.to::<JsStringLiteralExpression>()
-				.value_token()
-				.unwrap(),
-		),
-		SyntaxKind::JS_BOOLEAN_LITERAL_EXPRESSION => tokenize_token(
-			node.to::<JsBooleanLiteralExpression>()
-				.value_token()
-				.unwrap(),
-		),
+			tokenize_token(node.to::<JsLiteralMemberName>().value().ok()?)?
+		}
+		SyntaxKind::JS_STRING_LITERAL_EXPRESSION => {
+			tokenize_token(node.to::<JsStringLiteralExpression>().value_token().ok()?)?
+		}
+		SyntaxKind::JS_BOOLEAN_LITERAL_EXPRESSION => {
+			tokenize_token(node.to::<JsBooleanLiteralExpression>().value_token().ok()?)?
+		}
 		SyntaxKind::JS_NULL_LITERAL_EXPRESSION => {
-			tokenize_token(node.to::<JsNullLiteralExpression>().value_token().unwrap())
+			tokenize_token(node.to::<JsNullLiteralExpression>().value_token().ok()?)?
+		}
+		SyntaxKind::JS_NUMBER_LITERAL_EXPRESSION => {
+			tokenize_token(node.to::<JsNumberLiteralExpression>().value_token().ok()?)?
 		}
-		SyntaxKind::JS_NUMBER_LITERAL_EXPRESSION => tokenize_token(
-			node.to::<JsNumberLiteralExpression>()
-				.value_token()
-				.unwrap(),
-		),
 		SyntaxKind::JS_UNARY_EXPRESSION => {
-			let expr = JsUnaryExpression::cast(node).unwrap();
+			let expr = JsUnaryExpression::cast(node)?;
 			format_elements![
-				tokenize_token(expr.operator().unwrap()),
-				tokenize_node(expr.argument().unwrap().syntax().clone())
+				tokenize_token(expr.operator().ok()?)?,
+				tokenize_node(expr.argument().ok()?.syntax().clone())?
 			]
 		}
 
 		SyntaxKind::JS_PROPERTY_OBJECT_MEMBER => {
-			let prop = JsPropertyObjectMember::cast(node).unwrap();
+			let prop = JsPropertyObjectMember::cast(node)?;
 			format_elements![
-				tokenize_node(prop.name().unwrap().syntax().clone()),
+				tokenize_node(prop.name().ok()?.syntax().clone())?,
 				token(":"),
 				space_token(),
-				tokenize_node(prop.value().unwrap().syntax().clone()),
+				tokenize_node(prop.value().ok()?.syntax().clone())?,
 			]
 		}
 
 		SyntaxKind::JS_OBJECT_EXPRESSION => {
-			let object = JsObjectExpression::cast(node).unwrap();
+			let object = JsObjectExpression::cast(node)?;
 
 			let separator = format_elements![token(","), soft_line_break_or_space()];
 
-			let properties_list: Vec<FormatElement> = object
+			let properties_list = object
 				.members()
 				.iter()
-				.flatten()
-				.map(|prop| match prop {
+				.map(|prop| match prop.ok()? {
 					JsAnyObjectMember::JsPropertyObjectMember(prop) => {
-						format_elements![tokenize_node(prop.syntax().clone())]
+						tokenize_node(prop.syntax().clone())
 					}
-					_ => panic!("Unsupported prop type {:?}", prop),
+					_ => None,
 				})
-				.collect();
+				.collect::<Option<Vec<FormatElement>>>()?;
 
 			let properties = join_elements(separator, properties_list);
 
@@ -90,18 +130,17 @@ fn tokenize_node(node: SyntaxNode) -> FormatElement {
 			])
 		}
 		SyntaxKind::JS_ARRAY_EXPRESSION => {
-			let array = JsArrayExpression::cast(node).unwrap();
+			let array = JsArrayExpression::cast(node)?;
 
 			let separator = format_elements![token(","), soft_line_break_or_space(),];
 
-			let elements = join_elements(
-				separator,
-				array
-					.elements()
-					.iter()
-					.flatten()
-					.map(|element| tokenize_node(element.syntax().clone())),
-			);
+			let elements = array
+				.elements()
+				.iter()
+				.map(|element| tokenize_node(element.ok()?.syntax().clone()))
+				.collect::<Option<Vec<FormatElement>>>()?;
+
+			let elements = join_elements(separator, elements);
 
 			group_elements(format_elements![
 				token("["),
@@ -109,27 +148,43 @@ fn tokenize_node(node: SyntaxNode) -> FormatElement {
 				token("]"),
 			])
 		}
-		_ => panic!("Unsupported JSON kind: {:?}", node.kind()),
-	}
+		_ => return None,
+	};
+
+	Some(element)
+}
+
+/// Formats a JSON document. Returns `None` if the content isn't a JSON document.
+pub fn tokenize_json(content: &str) -> Option<FormatElement> {
+	let tokenized_content = tokenize_embedded_json(content)?;
+	Some(format_elements![tokenized_content, hard_line_break()])
 }
 
-pub fn tokenize_json(content: &str) -> FormatElement {
+/// Formats the content of a template literal tagged with `json`, for example the object in
+/// ``json`{ "a": 1 }` ``. Returns `None` if the content isn't a JSON document.
+pub(crate) fn tokenize_embedded_json(content: &str) -> Option<FormatElement> {
 	let script = parse_text(format!("({})", content).as_str(), 0);
 
-	// Unwrap the grouping to get to the JSON content. The grouping is only used as a trick to parse JSON
-	let json_content = JsParenthesizedExpression::cast(
-		script
-			.syntax()
-			.descendants()
-			.find(|e| e.kind() == SyntaxKind::JS_PARENTHESIZED_EXPRESSION)
-			.unwrap(),
-	)
-	// TODO: #1725 this should be reviewed for error handling
-	.and_then(|grouping| grouping.expression().ok())
-	.unwrap();
-
-	let tokenized_content = tokenize_node(json_content.syntax().clone());
-	format_elements![tokenized_content, hard_line_break()]
+	if !script.errors().is_empty() {
+		return None;
+	}
+
+	let mut statements = script.tree().statements().iter();
+
+	// The content must be a single expression, `1) + (2` isn't JSON even if it parses
+	let expression = match (statements.next(), statements.next()) {
+		(Some(JsAnyStatement::JsExpressionStatement(statement)), None) => {
+			statement.expression().ok()?
+		}
+		_ => return None,
+	};
+
+	match expression {
+		JsAnyExpression::JsParenthesizedExpression(parenthesized) => {
+			tokenize_node(parenthesized.expression().ok()?.syntax().clone())
+		}
+		_ => None,
+	}
 }
 
 #[cfg(test)]
@@ -144,14 +199,14 @@ mod test {
 
 	#[test]
 	fn tokenize_number() {
-		let result = tokenize_json("6.45");
+		let result = tokenize_json("6.45").unwrap();
 
 		assert_eq!(format_elements![token("6.45"), hard_line_break()], result);
 	}
 
 	#[test]
 	fn tokenize_string() {
-		let result = tokenize_json(r#""foo""#);
+		let result = tokenize_json(r#""foo""#).unwrap();
 
 		assert_eq!(
 			format_elements![token(r#""foo""#), hard_line_break()],
@@ -161,21 +216,21 @@ mod test {
 
 	#[test]
 	fn tokenize_boolean_false() {
-		let result = tokenize_json("false");
+		let result = tokenize_json("false").unwrap();
 
 		assert_eq!(format_elements![token("false"), hard_line_break()], result);
 	}
 
 	#[test]
 	fn tokenize_boolean_true() {
-		let result = tokenize_json("true");
+		let result = tokenize_json("true").unwrap();
 
 		assert_eq!(format_elements![token("true"), hard_line_break()], result);
 	}
 
 	#[test]
 	fn tokenize_boolean_null() {
-		let result = tokenize_json("null");
+		let result = tokenize_json("null").unwrap();
 
 		assert_eq!(format_elements![token("null"), hard_line_break()], result);
 	}
@@ -205,7 +260,7 @@ mod test {
 			hard_line_break(),
 		];
 
-		let result = tokenize_json(input);
+		let result = tokenize_json(input).unwrap();
 
 		assert_eq!(expected, result);
 	}
@@ -232,8 +287,30 @@ mod test {
 			hard_line_break(),
 		];
 
-		let result = tokenize_json(input);
+		let result = tokenize_json(input).unwrap();
 
 		assert_eq!(expected, result);
 	}
+
+	#[test]
+	fn tokenize_invalid_json() {
+		assert_eq!(tokenize_json("{ a: 1 }"), None);
+		assert_eq!(tokenize_json("[1, 2,, 3]"), None);
+		assert_eq!(tokenize_json("1) + (2"), None);
+		assert_eq!(tokenize_json("{ 'a': 0 }"), None);
+		assert_eq!(tokenize_json(r#"{ "a": 0x10 }"#), None);
+		for number in &["01", "1.", ".5", "1_000", "10n", "0b1", "0o7", "1e"] {
+			assert_eq!(tokenize_json(number), None, "{}", number);
+		}
+	}
+
+	#[test]
+	fn tokenize_json_numbers() {
+		for number in &["0", "10", "0.5", "1e10", "1.5E+10", "2e-3"] {
+			assert_eq!(
+				tokenize_json(number),
+				Some(format_elements![token(*number), hard_line_break()])
+			);
+		}
+	}
 }
@@ -1,10 +1,33 @@
 use crate::printer::Printer;
+use crate::ts::should_hug_last_argument;
+use crate::ts::statements::format_statement;
 use crate::{
-	concat_elements, format_elements, if_group_breaks, token, FormatElement, FormatOptions,
-	FormatResult, Formatted, ToFormatElement,
+	concat_elements, empty_element, empty_line, format_elements, hard_line_break, if_group_breaks,
+	indent, join_elements, line_suffix, source_marker, space_token, token, FormatElement,
+	FormatOptions, FormatResult, Formatted, QuoteStyle, Semicolons, ToFormatElement, TrailingComma,
 };
 use rome_rowan::SyntaxElement;
-use rslint_parser::{AstNode, AstSeparatedList, SyntaxNode, SyntaxToken};
+use rslint_errors::Diagnostic;
+use rslint_parser::ast::{ArgList, JsAnyStatement};
+use rslint_parser::{
+	AstNode, AstSeparatedList, SyntaxKind, SyntaxNode, SyntaxToken, SyntaxTriviaPiece, TextRange,
+	TextSize, T,
+};
+use rslint_text_edit::TextEdit;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// Whether a separated list accepts a comma after its last element, see [Formatter::format_separated]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum TrailingSeparator {
+	/// The list can't end with a separator, for example the declarators of a variable declaration
+	Disallowed,
+	/// The trailing comma is valid since ES5: it's printed unless the option is [TrailingComma::None]
+	Es5,
+	/// The trailing comma is valid since ES2017, after parameters and arguments, or in TypeScript
+	/// type parameters: it's only printed with [TrailingComma::All]
+	All,
+}
 
 /// Handles the formatting of a CST and stores the options how the CST should be formatted (user preferences).
 /// The formatter is passed to the [ToFormatElement] implementation of every node in the CST so that they
@@ -12,12 +35,21 @@ use rslint_parser::{AstNode, AstSeparatedList, SyntaxNode, SyntaxToken};
 #[derive(Debug, Default)]
 pub struct Formatter {
 	options: FormatOptions,
+	/// The kinds of the nodes that have been printed verbatim because their formatting isn't
+	/// supported yet, with the range of the first node of each kind
+	unsupported_kinds: RefCell<BTreeMap<SyntaxKind, TextRange>>,
+	/// The ranges of the suppression comments that don't explain why the node isn't formatted
+	unexplained_suppressions: RefCell<Vec<TextRange>>,
 }
 
 impl Formatter {
 	/// Creates a new context that uses the given formatter options
 	pub fn new(options: FormatOptions) -> Self {
-		Self { options }
+		Self {
+			options,
+			unsupported_kinds: RefCell::default(),
+			unexplained_suppressions: RefCell::default(),
+		}
 	}
 
 	/// Returns the [FormatOptions] specifying how to format the current CST
@@ -31,7 +63,130 @@ impl Formatter {
 		let element = self.format_syntax_node(root)?;
 
 		let printer = Printer::new(self.options);
-		Ok(printer.print(&element))
+		let mut formatted = printer.print(&element);
+
+		let unsupported_kinds = self.unsupported_kinds.into_inner();
+		if !unsupported_kinds.is_empty() {
+			formatted
+				.diagnostics
+				.push(unsupported_kinds_diagnostic(&unsupported_kinds));
+		}
+
+		for range in self.unexplained_suppressions.into_inner() {
+			formatted.diagnostics.push(
+				Diagnostic::warning(0, "Format", "suppression comment has no explanation")
+					.primary(range, "the reason is missing")
+					.footer_help(
+						"explain why the code isn't formatted: `// rome-ignore format: <reason>`",
+					),
+			);
+		}
+
+		Ok(formatted)
+	}
+
+	/// Formats the smallest statement, or list of statements, that covers `range` in the CST
+	/// and returns the edit that replaces the statements with their formatted code.
+	///
+	/// The statements are formatted at the indention level of their enclosing blocks. Their leading
+	/// and trailing comments are formatted with them. The whole CST is formatted if the range
+	/// covers all its statements, and no edit is returned if no statement intersects the range.
+	pub fn format_range(self, root: &SyntaxNode, range: TextRange) -> FormatResult<TextEdit> {
+		let covering_node = match root.covering_element(range) {
+			SyntaxElement::Node(node) => node,
+			SyntaxElement::Token(token) => token.parent().unwrap_or_else(|| root.clone()),
+		};
+		let mut ancestors = covering_node.ancestors();
+
+		let statements: Vec<_> = loop {
+			let node = match ancestors.next() {
+				Some(node) => node,
+				None => return Ok(TextEdit::default()),
+			};
+
+			if node.kind() == SyntaxKind::JS_ROOT {
+				let formatted = self.format_root(root)?;
+				return Ok(TextEdit::replace(
+					root.text_range(),
+					formatted.code().clone(),
+				));
+			}
+
+			if JsAnyStatement::can_cast(node.kind()) {
+				break vec![node];
+			}
+
+			if is_statement_list(&node) {
+				break node
+					.children()
+					.filter(|statement| statement.text_trimmed_range().intersect(range).is_some())
+					.collect();
+			}
+		};
+
+		let (first, last) = match (statements.first(), statements.last()) {
+			(Some(first), Some(last)) => (first, last),
+			_ => return Ok(TextEdit::default()),
+		};
+
+		let content = join_elements_keeping_empty_lines(
+			hard_line_break(),
+			statements
+				.iter()
+				.map(|statement| (statement.clone(), format_statement(statement, &self))),
+		);
+
+		// Starting with a line break sets the indention of the first line, as if the printer
+		// was already inside the blocks
+		let element = (0..self.indent_level(first)).fold(
+			format_elements![hard_line_break(), content],
+			|element, _| indent(element),
+		);
+
+		let formatted = Printer::new(self.options).print(&element);
+		let replaced_range = TextRange::new(
+			first
+				.first_token()
+				.map_or(first.text_trimmed_range().start(), |token| {
+					formatted_range(&token).start()
+				}),
+			last.last_token()
+				.map_or(last.text_trimmed_range().end(), |token| {
+					formatted_range(&token).end()
+				}),
+		);
+
+		Ok(TextEdit::replace(
+			replaced_range,
+			formatted.code().trim().to_string(),
+		))
+	}
+
+	/// Returns how many levels the statement is indented when the whole tree is formatted: one per
+	/// enclosing node that indents its content, for example one for the object and one for the
+	/// method body in `const o = { m() { a(); } }`.
+	fn indent_level(&self, statement: &SyntaxNode) -> usize {
+		statement
+			.ancestors()
+			.skip(1)
+			.filter(|ancestor| match ancestor.kind() {
+				SyntaxKind::JS_BLOCK_STATEMENT
+				| SyntaxKind::JS_FUNCTION_BODY
+				| SyntaxKind::TS_MODULE_BLOCK
+				| SyntaxKind::JS_SWITCH_STATEMENT
+				| SyntaxKind::JS_CASE_CLAUSE
+				| SyntaxKind::JS_DEFAULT_CLAUSE
+				| SyntaxKind::JS_CLASS_DECLARATION
+				| SyntaxKind::JS_CLASS_EXPRESSION
+				| SyntaxKind::JS_OBJECT_EXPRESSION
+				| SyntaxKind::JS_ARRAY_EXPRESSION => true,
+				// The last argument that is hugged isn't indented, `foo(() => {})`
+				SyntaxKind::ARG_LIST => {
+					!matches!(ArgList::cast(ancestor.clone()), Some(args) if should_hug_last_argument(&args))
+				}
+				_ => false,
+			})
+			.count()
 	}
 
 	fn format_syntax_node(&self, node: &SyntaxNode) -> FormatResult<FormatElement> {
@@ -48,32 +203,88 @@ impl Formatter {
 	///
 	/// Returns `None` if the node couldn't be formatted because of syntax errors in its sub tree.
 	/// The parent may use `format_raw` to insert the node content as is.
+	///
+	/// A node preceded by a `// rome-ignore format: <reason>` or `// prettier-ignore` comment
+	/// is printed as it is in the source, see [Formatter::format_suppressed].
 	pub fn format_node<T: AstNode + ToFormatElement>(
 		&self,
 		node: T,
 	) -> FormatResult<FormatElement> {
+		let content = match self.format_suppressed(node.syntax()) {
+			Some(suppressed) => suppressed,
+			None => node.to_format_element(self)?,
+		};
+
 		Ok(concat_elements(vec![
 			self.format_node_start(node.syntax()),
-			node.to_format_element(self)?,
+			content,
 			self.format_node_end(node.syntax()),
 		]))
 	}
 
+	/// Prints the node as it is in the source, indentation included, if a leading comment of its
+	/// first token is a suppression comment. The leading comments themselves are formatted.
+	///
+	/// Returns `None` if the node isn't suppressed.
+	fn format_suppressed(&self, node: &SyntaxNode) -> Option<FormatElement> {
+		let mut tokens = node.descendants_tokens();
+		let first_token = tokens.next()?;
+
+		let suppression = first_token.leading_trivia().pieces().find_map(|piece| {
+			piece.as_comments()?;
+			parse_suppression_comment(piece.text()).map(|suppression| (piece, suppression))
+		});
+
+		match suppression? {
+			(_, Suppression::Explained) => {}
+			(piece, Suppression::MissingReason) => {
+				// A node is formatted more than once if its parent tries multiple layouts
+				let mut unexplained = self.unexplained_suppressions.borrow_mut();
+				if !unexplained.contains(&piece.text_range()) {
+					unexplained.push(piece.text_range());
+				}
+			}
+		}
+
+		// The leading trivia of the first token is replaced by its formatted comments
+		Some(
+			format_elements![
+				self.format_leading_comments(&first_token),
+				concat_elements(std::iter::once(format_raw_token(&first_token).1).chain(
+					tokens.map(|syntax_token| {
+						let (leading, rest) = format_raw_token(&syntax_token);
+						format_elements![leading, rest]
+					})
+				))
+			]
+			.trim_end(),
+		)
+	}
+
 	/// Helper function that returns what should be printed before the node that work on
 	/// the non-generic [SyntaxNode] to avoid unrolling the logic for every [AstNode] type.
 	fn format_node_start(&self, _node: &SyntaxNode) -> FormatElement {
-		// TODO: Set the marker for the start source map location, add leading comments, ...
-		concat_elements(vec![])
+		// The start of the node is mapped by the source marker of its first token, which is printed
+		// after the leading comments of the token, see [Formatter::format_token]
+		empty_element()
 	}
 
 	/// Helper function that returns what should be printed after the node that work on
 	/// the non-generic [SyntaxNode] to avoid unrolling the logic for every [AstNode] type.
-	fn format_node_end(&self, _node: &SyntaxNode) -> FormatElement {
-		// TODO: Sets the marker for the end source map location, add trailing comments, ...
-		concat_elements(vec![])
+	pub(crate) fn format_node_end(&self, node: &SyntaxNode) -> FormatElement {
+		source_marker(node.text_trimmed_range().end())
 	}
 
-	/// Formats the passed in token.
+	/// Formats the passed in token, with the comments of its leading and trailing trivia.
+	///
+	/// Comments are printed where they are in the source:
+	/// - leading comments are printed before the token. A comment that is on its own line in the
+	///   source stays on its own line, a block comment that is on the same line as the token stays inline
+	/// - trailing comments are printed after the token, on the same line
+	/// - leading comments of a closing `}`, `)` or `]` are dangling comments: nothing follows them
+	///   in the list or block the token closes, so they are indented like its content
+	///
+	/// A line comment always forces a line break after it.
 	///
 	/// May return `None` if the token wasn't present in the original source but was inserted
 	/// by the parser to "fix" a syntax error and generate a valid tree.
@@ -82,7 +293,7 @@ impl Formatter {
 	///
 	/// ```
 	///
-	/// use rome_formatter::{Formatter, token};
+	/// use rome_formatter::{format_elements, source_marker, Formatter, token};
 	/// use rslint_parser::{SyntaxNode, T, SyntaxToken, JsLanguage, SyntaxKind};
 	/// use rome_rowan::{NodeOrToken, TreeBuilder};
 	///
@@ -97,10 +308,179 @@ impl Formatter {
 	/// let formatter = Formatter::default();
 	/// let result = formatter.format_token(&syntax_token);
 	///
-	/// assert_eq!(Ok(token("'abc'")), result)
+	/// assert_eq!(
+	///     Ok(format_elements![source_marker(0.into()), token("'abc'")]),
+	///     result
+	/// )
 	/// ```
 	pub fn format_token(&self, syntax_token: &SyntaxToken) -> FormatResult<FormatElement> {
-		Ok(token(syntax_token.text_trimmed()))
+		Ok(self.format_replaced(syntax_token, token(syntax_token.text_trimmed())))
+	}
+
+	/// Formats a token whose text is replaced by `content`, for example a string literal
+	/// printed with different quotes. The comments of the token are kept as in [Formatter::format_token].
+	pub fn format_replaced(
+		&self,
+		syntax_token: &SyntaxToken,
+		content: FormatElement,
+	) -> FormatElement {
+		format_elements![
+			self.format_leading_comments(syntax_token),
+			source_marker(syntax_token.text_trimmed_range().start()),
+			content,
+			self.format_trailing_comments(syntax_token)
+		]
+	}
+
+	/// Formats a string literal token, for example the source of an import.
+	///
+	/// The string uses the quotes of the [crate::QuoteStyle] of the options, unless it contains
+	/// more of these quotes than of the other ones. The escapes of the quotes are rewritten for the
+	/// chosen quotes, the value of the string doesn't change.
+	pub fn format_string_literal(&self, syntax_token: &SyntaxToken) -> FormatElement {
+		let quoted = syntax_token.text_trimmed();
+		let normalized = normalize_string_literal(quoted, self.options.quote_style);
+
+		self.format_replaced(syntax_token, token(normalized))
+	}
+
+	/// Formats the token if it's present in the source or prints `text` in its place otherwise,
+	/// for example for an optional semicolon.
+	pub fn format_or_insert_token(
+		&self,
+		syntax_token: Option<SyntaxToken>,
+		text: &'static str,
+	) -> FormatElement {
+		match syntax_token {
+			Some(syntax_token) => self.format_replaced(&syntax_token, token(text)),
+			None => token(text),
+		}
+	}
+
+	/// Formats the semicolon at the end of `statement`. It's inserted if it's missing in the source
+	/// or removed, depending on the [crate::Semicolons] option.
+	///
+	/// A semicolon is always needed when the statement is followed by a token on the same line:
+	/// the `else` of `if (a) b(); else c();` and the `while` of `do a(); while (b);`
+	pub fn format_semicolon<T: AstNode>(
+		&self,
+		statement: &T,
+		semicolon: Option<SyntaxToken>,
+	) -> FormatElement {
+		let semicolons = match self.options.semicolons {
+			Semicolons::AsNeeded if is_followed_on_the_same_line(statement.syntax()) => {
+				Semicolons::Always
+			}
+			semicolons => semicolons,
+		};
+
+		match (semicolons, semicolon) {
+			(Semicolons::Always, semicolon) => self.format_or_insert_token(semicolon, ";"),
+			(Semicolons::AsNeeded, Some(semicolon)) => {
+				self.format_replaced(&semicolon, empty_element())
+			}
+			(Semicolons::AsNeeded, None) => empty_element(),
+		}
+	}
+
+	fn format_leading_comments(&self, syntax_token: &SyntaxToken) -> FormatElement {
+		let mut elements = vec![];
+		let mut line_breaks = 0;
+
+		let is_dangling = matches!(syntax_token.kind(), T!['}'] | T![')'] | T![']']);
+		// The comments at the end of a block or list that isn't empty
+		let follows_content = is_dangling
+			&& matches!(
+				syntax_token.prev_token(),
+				Some(previous) if !matches!(previous.kind(), T!['{'] | T!['('] | T!['['])
+			);
+
+		for piece in syntax_token.leading_trivia().pieces() {
+			if piece.as_comments().is_none() {
+				line_breaks += count_line_breaks(piece.text());
+				continue;
+			}
+
+			// An empty line between two comments is kept. The one before the first comment
+			// is handled by the list that contains the node, unless the comments end the list
+			if line_breaks > 1 && (follows_content || !elements.is_empty()) {
+				elements.push(empty_line());
+			} else if line_breaks > 0 {
+				elements.push(hard_line_break());
+			} else if !elements.is_empty() {
+				elements.push(space_token());
+			}
+
+			elements.push(format_comment(&piece));
+			if is_line_comment(&piece) {
+				elements.push(hard_line_break());
+			}
+			line_breaks = 0;
+		}
+
+		if elements.is_empty() {
+			return empty_element();
+		}
+
+		if is_dangling {
+			// Dangling comments: the token closes an empty block or list, or the comments
+			// follow its last element
+			format_elements![indent(concat_elements(elements)), hard_line_break()]
+		} else if line_breaks > 1 {
+			format_elements![concat_elements(elements), empty_line()]
+		} else if line_breaks > 0 {
+			format_elements![concat_elements(elements), hard_line_break()]
+		} else {
+			format_elements![concat_elements(elements), space_token()]
+		}
+	}
+
+	fn format_trailing_comments(&self, syntax_token: &SyntaxToken) -> FormatElement {
+		// The block comments that follow an opening parenthesis or bracket stick to it and are
+		// separated from what follows them, `foo(/* a */ b)`, unless they're all the list contains
+		let is_opening = matches!(syntax_token.kind(), T!['('] | T!['[']);
+		// The list between the brackets is an empty node, it has no token
+		let next_token = std::iter::successors(syntax_token.next_sibling_or_token(), |element| {
+			element.next_sibling_or_token()
+		})
+		.find_map(|element| match element {
+			SyntaxElement::Node(node) => node.first_token(),
+			SyntaxElement::Token(token) => Some(token),
+		});
+		let is_empty_list = matches!(
+			next_token.map(|next_token| next_token.kind()),
+			Some(T![')'] | T![']'])
+		);
+
+		let mut elements = vec![];
+
+		for piece in syntax_token.trailing_trivia().pieces() {
+			if piece.as_comments().is_none() {
+				continue;
+			}
+
+			// The trailing trivia ends before the next line break, so that a line
+			// comment is always the last trailing comment. It's printed at the end of
+			// the line, after the content that follows the token, e.g. a semicolon
+			if is_line_comment(&piece) {
+				elements.push(line_suffix(format_elements![
+					space_token(),
+					format_comment(&piece)
+				]));
+			} else if is_opening {
+				elements.push(format_comment(&piece));
+				elements.push(if is_empty_list {
+					empty_element()
+				} else {
+					space_token()
+				});
+			} else {
+				elements.push(space_token());
+				elements.push(format_comment(&piece));
+			}
+		}
+
+		concat_elements(elements)
 	}
 
 	/// Formats each child and returns the result as a list.
@@ -124,21 +504,43 @@ impl Formatter {
 		Ok(result.into_iter())
 	}
 
+	/// Formats the elements of a separated list with their separators.
+	///
+	/// The separators of the source are kept, except the one after the last element: a trailing comma
+	/// is printed if the group of the list breaks, the list accepts it and the [crate::TrailingComma]
+	/// option allows it. A rest element is never followed by a comma, `(...rest,)` is a syntax error,
+	/// and a hole always keeps its comma, `[a, ,]` has two elements but `[a, ]` only one.
 	pub fn format_separated<T: AstNode + ToFormatElement + Clone>(
 		&self,
 		list: AstSeparatedList<T>,
+		trailing_separator: TrailingSeparator,
 	) -> FormatResult<impl Iterator<Item = FormatElement>> {
 		let mut result = Vec::with_capacity(list.len());
+		let last_index = list.len().saturating_sub(1);
 
 		for (index, element) in list.elements().enumerate() {
-			let node = self.format_node(element.node()?)?;
-			if let Some(separator) = element.trailing_separator()? {
-				let formatted_separator = self.format_token(&separator)?;
-				if index == list.len() - 1 {
-					result.push(format_elements![node, if_group_breaks(formatted_separator)])
+			let node = element.node()?;
+			let is_rest = is_rest_element(node.syntax().kind());
+			let is_hole = node.syntax().kind() == SyntaxKind::JS_ARRAY_HOLE;
+			let node = self.format_node(node)?;
+			let separator = element.trailing_separator()?;
+
+			if index == last_index && !is_hole {
+				let trailing_comma = if !is_rest && self.prints_trailing_comma(trailing_separator) {
+					if_group_breaks(token(","))
 				} else {
-					result.push(format_elements![node, formatted_separator]);
-				}
+					empty_element()
+				};
+
+				// The comments of the separator are printed even if the comma isn't
+				let trailing_comma = match separator {
+					Some(separator) => self.format_replaced(&separator, trailing_comma),
+					None => trailing_comma,
+				};
+
+				result.push(format_elements![node, trailing_comma]);
+			} else if let Some(separator) = separator {
+				result.push(format_elements![node, self.format_token(&separator)?]);
 			} else {
 				result.push(node);
 			}
@@ -147,21 +549,300 @@ impl Formatter {
 		Ok(result.into_iter())
 	}
 
+	fn prints_trailing_comma(&self, trailing_separator: TrailingSeparator) -> bool {
+		match trailing_separator {
+			TrailingSeparator::Disallowed => false,
+			TrailingSeparator::Es5 => self.options.trailing_comma != TrailingComma::None,
+			TrailingSeparator::All => self.options.trailing_comma == TrailingComma::All,
+		}
+	}
+
+	/// Prints a node as it is in the source code, without its leading and trailing whitespace.
+	///
+	/// It's used for the nodes that can't be formatted, for example because they contain syntax errors.
+	/// See [Formatter::format_raw] for the risks of formatting such nodes.
+	pub fn format_verbatim(&self, node: &SyntaxNode) -> FormatElement {
+		self.format_raw(node).trim_start().trim_end()
+	}
+
+	/// Prints a node whose formatting isn't implemented yet as it is in the source code.
+	///
+	/// The kind of the node is recorded, so that [Formatter::format_root] can warn that parts
+	/// of the code haven't been formatted.
+	pub fn format_unsupported(&self, node: &SyntaxNode) -> FormatElement {
+		self.unsupported_kinds
+			.borrow_mut()
+			.entry(node.kind())
+			.or_insert_with(|| node.text_trimmed_range());
+
+		self.format_verbatim(node)
+	}
+
 	/// "Formats" a node according to its original formatting in the source text. Being able to format
 	/// a node "as is" is useful if a node contains syntax errors. Formatting a node with syntax errors
 	/// has the risk that Rome misinterprets the structure of the code and formatting it could
 	/// "mess up" the developers, yet incomplete, work or accidentally introduce new syntax errors.
 	///
 	/// You may be inclined to call `node.text` directly. However, using `text` doesn't track the nodes
-	///nor its children source mapping information, resulting in incorrect source maps for this subtree.
+	/// nor its children source mapping information, resulting in incorrect source maps for this subtree.
 	pub fn format_raw(&self, node: &SyntaxNode) -> FormatElement {
-		concat_elements(node.children_with_tokens().map(|child| match child {
-			SyntaxElement::Node(child_node) => {
-				// TODO: Add source map markers before/after node as well as any additional elements that
-				// need to be tracked for every node.
-				self.format_raw(&child_node)
-			}
-			SyntaxElement::Token(syntax_token) => token(syntax_token.text()),
+		concat_elements(node.descendants_tokens().map(|syntax_token| {
+			let (leading, rest) = format_raw_token(&syntax_token);
+			format_elements![leading, rest]
 		}))
 	}
 }
+
+/// Returns the text of the token's leading trivia and the rest of the token as it is in the source,
+/// with the source markers of the token.
+///
+/// The leading trivia is a separate token, so that the marker points to the token itself even
+/// if the leading whitespace is trimmed by [Formatter::format_verbatim]
+fn format_raw_token(syntax_token: &SyntaxToken) -> (FormatElement, FormatElement) {
+	let range = syntax_token.text_range();
+	let trimmed_range = syntax_token.text_trimmed_range();
+	let text = syntax_token.text();
+	let relative = |offset: TextSize| usize::from(offset - range.start());
+
+	(
+		token(&text[..relative(trimmed_range.start())]),
+		format_elements![
+			source_marker(trimmed_range.start()),
+			token(&text[relative(trimmed_range.start())..relative(trimmed_range.end())]),
+			source_marker(trimmed_range.end()),
+			token(&text[relative(trimmed_range.end())..]),
+		],
+	)
+}
+
+/// A comment that disables the formatting of the node that follows it
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum Suppression {
+	/// `// rome-ignore format: <reason>` or `// prettier-ignore`
+	Explained,
+	/// `// rome-ignore format` without a reason
+	MissingReason,
+}
+
+/// Whether the statement is followed by a token that is printed on the same line: the `else` of
+/// an `if` or the `while` of a `do..while` loop
+fn is_followed_on_the_same_line(statement: &SyntaxNode) -> bool {
+	let next_token = statement
+		.last_token()
+		.and_then(|last_token| last_token.next_token());
+
+	match next_token {
+		Some(next_token) => match next_token.kind() {
+			T![else] => true,
+			T![while] => matches!(
+				next_token.parent().map(|parent| parent.kind()),
+				Some(SyntaxKind::JS_DO_WHILE_STATEMENT)
+			),
+			_ => false,
+		},
+		None => false,
+	}
+}
+
+/// Parses the text of a line or block comment, returns `None` if it isn't a suppression comment
+fn parse_suppression_comment(comment: &str) -> Option<Suppression> {
+	let content = match comment.strip_prefix("//") {
+		Some(content) => content,
+		None => comment.strip_prefix("/*")?.strip_suffix("*/")?,
+	}
+	.trim();
+
+	// The comment Prettier uses, accepted so that existing code keeps its formatting
+	if content == "prettier-ignore" {
+		return Some(Suppression::Explained);
+	}
+
+	let rest = content.strip_prefix("rome-ignore format")?;
+	match rest.strip_prefix(':') {
+		Some(reason) if !reason.trim().is_empty() => Some(Suppression::Explained),
+		Some(_) => Some(Suppression::MissingReason),
+		None if rest.trim().is_empty() => Some(Suppression::MissingReason),
+		None => None,
+	}
+}
+
+/// Returns the string literal `quoted` with the `preferred` quotes, or with the other quotes if
+/// they require fewer escapes: `'a "b"'` stays as it is with double quotes.
+///
+/// The escaped quotes of the content are only kept if they're the enclosing quotes, `'it\'s'`
+/// becomes `"it's"`, and the unescaped enclosing quotes are escaped.
+fn normalize_string_literal(quoted: &str, preferred: QuoteStyle) -> String {
+	let content = match (quoted.chars().next(), quoted.chars().last()) {
+		(Some(start @ ('"' | '\'')), Some(end)) if start == end && quoted.len() >= 2 => {
+			&quoted[1..quoted.len() - 1]
+		}
+		// An unterminated string of code with syntax errors
+		_ => return String::from(quoted),
+	};
+
+	let preferred_quote = preferred.as_char();
+	let other_quote = preferred.other().as_char();
+
+	let quote = if content.matches(preferred_quote).count() > content.matches(other_quote).count() {
+		other_quote
+	} else {
+		preferred_quote
+	};
+
+	let mut normalized = String::with_capacity(quoted.len());
+	normalized.push(quote);
+
+	let mut chars = content.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' => match chars.next() {
+				Some(escaped @ ('"' | '\'')) if escaped != quote => normalized.push(escaped),
+				Some(escaped) => {
+					normalized.push('\\');
+					normalized.push(escaped);
+				}
+				None => normalized.push('\\'),
+			},
+			c if c == quote => {
+				normalized.push('\\');
+				normalized.push(c);
+			}
+			c => normalized.push(c),
+		}
+	}
+
+	normalized.push(quote);
+	normalized
+}
+
+/// Returns `true` if the node is the list of statements of a script, a block or a switch clause
+fn is_statement_list(node: &SyntaxNode) -> bool {
+	node.kind() == SyntaxKind::LIST
+		&& matches!(node.first_child(), Some(child) if JsAnyStatement::can_cast(child.kind()))
+}
+
+/// Returns the range of the token including the comments that [Formatter::format_token] prints with it
+fn formatted_range(syntax_token: &SyntaxToken) -> TextRange {
+	let comments_range = |pieces: &mut dyn Iterator<Item = SyntaxTriviaPiece>| {
+		pieces
+			.filter(|piece| piece.as_comments().is_some())
+			.map(|piece| piece.text_range())
+			.reduce(|first, last| first.cover(last))
+	};
+
+	let mut range = syntax_token.text_trimmed_range();
+	if let Some(leading) = comments_range(&mut syntax_token.leading_trivia().pieces()) {
+		range = range.cover(leading);
+	}
+	if let Some(trailing) = comments_range(&mut syntax_token.trailing_trivia().pieces()) {
+		range = range.cover(trailing);
+	}
+	range
+}
+
+/// Joins the formatted nodes of a list with `separator`, or with an empty line where the source has
+/// at least one empty line before the node, so that the groups of statements or members written by
+/// the developer are kept. Like [join_elements], it skips the empty elements.
+pub(crate) fn join_elements_keeping_empty_lines(
+	separator: FormatElement,
+	elements: impl IntoIterator<Item = (SyntaxNode, FormatElement)>,
+) -> FormatElement {
+	let mut result = Vec::new();
+
+	for (node, element) in elements {
+		if element.is_empty() {
+			continue;
+		}
+
+		if !result.is_empty() {
+			if has_empty_line_before(&node) {
+				result.push(empty_line());
+			} else {
+				result.push(separator.clone());
+			}
+		}
+
+		result.push(element);
+	}
+
+	concat_elements(result)
+}
+
+/// Returns `true` if the leading trivia of the node contains an empty line before its first comment
+fn has_empty_line_before(node: &SyntaxNode) -> bool {
+	let first_token = match node.first_token() {
+		Some(first_token) => first_token,
+		None => return false,
+	};
+
+	let mut line_breaks = 0;
+	for piece in first_token.leading_trivia().pieces() {
+		if piece.as_comments().is_some() {
+			break;
+		}
+
+		line_breaks += count_line_breaks(piece.text());
+	}
+
+	line_breaks > 1
+}
+
+fn count_line_breaks(text: &str) -> usize {
+	text.matches('\n').count() + text.matches('\r').count() - text.matches("\r\n").count()
+}
+
+/// Returns `true` if the node is a rest element, that must be the last element of its list
+fn is_rest_element(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		SyntaxKind::JS_REST_PARAMETER
+			| SyntaxKind::JS_ARRAY_REST_BINDING
+			| SyntaxKind::JS_OBJECT_REST_BINDING
+			| SyntaxKind::JS_ARRAY_ASSIGNMENT_TARGET_REST_ELEMENT
+			| SyntaxKind::JS_OBJECT_REST_PROPERTY_ASSIGNMENT_TARGET
+	)
+}
+
+fn is_line_comment(comment: &SyntaxTriviaPiece) -> bool {
+	comment.text().starts_with("//")
+}
+
+/// Formats a comment as it is in the source, except for the block comments whose lines all start
+/// with a `*` (e.g. JSDoc comments): the lines are re-indented to align the `*` at the current indention.
+fn format_comment(comment: &SyntaxTriviaPiece) -> FormatElement {
+	let text = comment.text();
+	let mut lines = text.lines();
+	let first_line = lines.next().unwrap_or_default();
+
+	let is_doc_comment = text.starts_with("/*")
+		&& lines.clone().count() > 0
+		&& lines.clone().all(|line| line.trim_start().starts_with('*'));
+
+	if !is_doc_comment {
+		return token(text);
+	}
+
+	join_elements(
+		hard_line_break(),
+		std::iter::once(token(first_line.trim_end()))
+			.chain(lines.map(|line| token(format!(" {}", line.trim())))),
+	)
+}
+
+/// Creates the warning listing the kinds of nodes that have been printed verbatim
+fn unsupported_kinds_diagnostic(unsupported_kinds: &BTreeMap<SyntaxKind, TextRange>) -> Diagnostic {
+	let kinds: Vec<_> = unsupported_kinds
+		.keys()
+		.map(|kind| format!("{:?}", kind))
+		.collect();
+
+	let mut diagnostic = Diagnostic::warning(
+		0,
+		"Format",
+		"some nodes aren't supported by the formatter and have been left untouched",
+	);
+	for (kind, range) in unsupported_kinds {
+		diagnostic = diagnostic.secondary(*range, format!("{:?} isn't supported", kind));
+	}
+	diagnostic.footer_note(format!("unsupported node kinds: {}", kinds.join(", ")))
+}
@@ -54,25 +54,33 @@ mod format_json;
 mod formatter;
 mod intersperse;
 mod printer;
+mod source_map;
 mod ts;
 
 use crate::format_json::tokenize_json;
 
-pub use formatter::Formatter;
+pub use formatter::{Formatter, TrailingSeparator};
+use rslint_errors::Diagnostic;
 use rslint_parser::SyntaxError;
 
 pub use format_element::{
-	block_indent, concat_elements, empty_element, group_elements, hard_line_break, if_group_breaks,
-	if_group_fits_on_single_line, indent, join_elements, soft_indent, soft_line_break,
-	soft_line_break_or_space, space_token, token, FormatElement,
+	best_fitting, block_indent, concat_elements, empty_element, empty_line, fill_elements,
+	group_elements, hard_line_break, if_group_breaks, if_group_fits_on_single_line, indent,
+	join_elements, line_suffix, line_suffix_boundary, soft_indent, soft_line_break,
+	soft_line_break_or_space, source_marker, space_token, token, FormatElement,
 };
+pub use printer::unicode_char_width;
+pub use printer::LineEnding;
 pub use printer::Printer;
 pub use printer::PrinterOptions;
 use rome_core::file_handlers::Language;
 use rome_core::App;
 use rome_path::RomePath;
-use rslint_parser::parse_text;
+use rslint_parser::{parse_with_syntax, Syntax, SyntaxKind, SyntaxNode, TextRange, T};
+use rslint_text_edit::TextEdit;
+pub use source_map::{SourceMap, SourceMapping};
 
+use std::fmt;
 use std::io::Read;
 use std::str::FromStr;
 
@@ -95,6 +103,27 @@ pub enum FormatError {
 
 	/// When the ability to format the current file has been turned off on purpose
 	CapabilityDisabled,
+
+	/// The code contains syntax errors, and formatting code with errors is disabled.
+	/// Contains the diagnostics of the errors reported by the parser
+	SyntaxError(Vec<Diagnostic>),
+
+	/// The content of a JSON file isn't a JSON document
+	InvalidJson,
+}
+
+impl fmt::Display for FormatError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			FormatError::MissingRequiredChild => write!(f, "missing required child"),
+			FormatError::UnsupportedLanguage => write!(f, "language not supported"),
+			FormatError::CapabilityDisabled => write!(f, "formatting capability not enabled"),
+			FormatError::SyntaxError(diagnostics) => {
+				write!(f, "the code contains {} syntax error(s)", diagnostics.len())
+			}
+			FormatError::InvalidJson => write!(f, "the content isn't a valid JSON document"),
+		}
+	}
 }
 
 impl From<SyntaxError> for FormatError {
@@ -132,13 +161,169 @@ impl FromStr for IndentStyle {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum QuoteStyle {
+	/// Uses double quotes for the strings: `"abc"`
+	Double,
+	/// Uses single quotes for the strings: `'abc'`
+	Single,
+}
+
+impl QuoteStyle {
+	/// Returns the quote character of this style
+	pub const fn as_char(&self) -> char {
+		match self {
+			QuoteStyle::Double => '"',
+			QuoteStyle::Single => '\'',
+		}
+	}
+
+	/// Returns the style using the other quotes
+	pub const fn other(&self) -> QuoteStyle {
+		match self {
+			QuoteStyle::Double => QuoteStyle::Single,
+			QuoteStyle::Single => QuoteStyle::Double,
+		}
+	}
+}
+
+impl Default for QuoteStyle {
+	fn default() -> Self {
+		Self::Double
+	}
+}
+
+impl FromStr for QuoteStyle {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"double" => Ok(Self::Double),
+			"single" => Ok(Self::Single),
+			_ => Err("Value not supported for QuoteStyle"),
+		}
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Semicolons {
+	/// Prints a semicolon at the end of every statement
+	Always,
+	/// Only prints the semicolons that are needed: the statements that start with `(`, `[`, `` ` ``,
+	/// `+`, `-` or `/` are prefixed with a semicolon, so that they don't continue the previous statement
+	AsNeeded,
+}
+
+impl Default for Semicolons {
+	fn default() -> Self {
+		Self::Always
+	}
+}
+
+impl FromStr for Semicolons {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"always" => Ok(Self::Always),
+			"as-needed" => Ok(Self::AsNeeded),
+			_ => Err("Value not supported for Semicolons"),
+		}
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum TrailingComma {
+	/// Never prints a comma after the last element of a list
+	None,
+	/// Prints a trailing comma in the lists where ES5 accepts it: arrays, objects, imports and
+	/// exports, enums and tuples
+	Es5,
+	/// Prints a trailing comma in all the lists that accept it, the parameters, the arguments
+	/// and the type parameters included
+	All,
+}
+
+impl Default for TrailingComma {
+	fn default() -> Self {
+		Self::Es5
+	}
+}
+
+impl FromStr for TrailingComma {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"none" => Ok(Self::None),
+			"es5" => Ok(Self::Es5),
+			"all" => Ok(Self::All),
+			_ => Err("Value not supported for TrailingComma"),
+		}
+	}
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SourceType {
+	/// The code is a script, it can rely on the sloppy mode, for example on `with` statements
+	Script,
+	/// The code is an ES module, it can contain `import` and `export` declarations
+	Module,
+	/// The code is a module if it contains `import` or `export` declarations, a script otherwise
+	Auto,
+}
+
+impl Default for SourceType {
+	fn default() -> Self {
+		Self::Auto
+	}
+}
+
+impl FromStr for SourceType {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"script" => Ok(Self::Script),
+			"module" => Ok(Self::Module),
+			"auto" => Ok(Self::Auto),
+			_ => Err("Value not supported for SourceType"),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
 pub struct FormatOptions {
 	/// The indent style
 	pub indent_style: IndentStyle,
 
 	/// What's the max width of a line. Defaults to 80
 	pub line_width: u16,
+
+	/// The line ending of the formatted code. Defaults to [LineEnding::LineFeed]
+	pub line_ending: LineEnding,
+
+	/// The quotes used for the string literals. Defaults to [QuoteStyle::Double]
+	pub quote_style: QuoteStyle,
+
+	/// Where the statements end with a semicolon. Defaults to [Semicolons::Always]
+	pub semicolons: Semicolons,
+
+	/// Where a comma is printed after the last element of a list that is broken over multiple
+	/// lines. Defaults to [TrailingComma::Es5]
+	pub trailing_comma: TrailingComma,
+
+	/// How the `.js` files are parsed, the `.mjs` files are always modules and the `.cjs` files
+	/// always scripts. Defaults to [SourceType::Auto]
+	pub source_type: SourceType,
+
+	/// Whether code with syntax errors should be formatted. The erroneous nodes are printed
+	/// as they are in the source code. Defaults to `false`
+	pub format_with_errors: bool,
+
+	/// Whether the content of the template literals tagged with `json`, ``json`{ "a": 1 }` ``,
+	/// is formatted as a JSON document. Defaults to `false`
+	pub format_embedded_json: bool,
 }
 
 impl FormatOptions {
@@ -155,25 +340,60 @@ impl Default for FormatOptions {
 		Self {
 			indent_style: IndentStyle::default(),
 			line_width: 80,
+			line_ending: LineEnding::LineFeed,
+			quote_style: QuoteStyle::default(),
+			semicolons: Semicolons::default(),
+			trailing_comma: TrailingComma::default(),
+			source_type: SourceType::default(),
+			format_with_errors: false,
+			format_embedded_json: false,
 		}
 	}
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Formatted {
 	code: String,
+	diagnostics: Vec<Diagnostic>,
+	mappings: Vec<SourceMapping>,
 }
 
 impl Formatted {
 	pub fn new(code: &str) -> Self {
 		Self {
 			code: String::from(code),
+			diagnostics: Vec::new(),
+			mappings: Vec::new(),
 		}
 	}
 
+	pub(crate) fn with_mappings(mut self, mappings: Vec<SourceMapping>) -> Self {
+		self.mappings = mappings;
+		self
+	}
+
 	pub fn code(&self) -> &String {
 		&self.code
 	}
+
+	/// The warnings about the code that couldn't be formatted, for example because the formatter
+	/// doesn't support it yet
+	pub fn diagnostics(&self) -> &[Diagnostic] {
+		&self.diagnostics
+	}
+
+	/// The positions in the source code of the printed [source_marker]s, ordered by their
+	/// position in the formatted code
+	pub fn mappings(&self) -> &[SourceMapping] {
+		&self.mappings
+	}
+
+	/// Creates the source map from the formatted code to `source`, the code that has been formatted.
+	///
+	/// `source_name` is the name of the source file, as it should appear in the source map.
+	pub fn source_map(&self, source_name: &str, source: &str) -> SourceMap {
+		SourceMap::new(source_name, source, &self.code, &self.mappings)
+	}
 }
 
 // TODO: implement me + handle errors
@@ -186,27 +406,139 @@ pub fn format(rome_path: &mut RomePath, options: FormatOptions) -> FormatResult<
 	file.read_to_string(&mut buffer)
 		.expect("cannot read the file to format");
 
-	if let Some(handler) = rome_path.get_handler() {
-		if handler.capabilities().format {
-			let result = match handler.language() {
-				Language::Js => {
-					let parsed_result = parse_text(buffer.as_str(), 0);
-					Formatter::new(options).format_root(&parsed_result.syntax())
-				}
-				Language::Json => {
-					let element = tokenize_json(buffer.as_str());
-					Ok(format_element(&element, options))
-				}
-				Language::Ts | Language::Unknown => Err(FormatError::UnsupportedLanguage),
-			};
-
-			result
-		} else {
-			Err(FormatError::CapabilityDisabled)
-		}
-	} else {
-		Err(FormatError::UnsupportedLanguage)
+	format_source(rome_path, buffer.as_str(), options)
+}
+
+/// Formats `source` as if it was the content of the file at `rome_path`.
+///
+/// The file itself is never read: the path is only used to retrieve the handler that
+/// tells which language `source` is written in.
+pub fn format_source(
+	rome_path: &RomePath,
+	source: &str,
+	mut options: FormatOptions,
+) -> FormatResult<Formatted> {
+	if options.line_ending == LineEnding::Auto {
+		options.line_ending = LineEnding::detect(source);
 	}
+
+	match formatted_language(rome_path)? {
+		Language::Js => {
+			let root = parse_js(source, source_type(rome_path, &options), &options)?;
+			Formatter::new(options).format_root(&root)
+		}
+		Language::Ts => {
+			let root = parse_ts(source, &options)?;
+			Formatter::new(options).format_root(&root)
+		}
+		Language::Json => {
+			let element = tokenize_json(source).ok_or(FormatError::InvalidJson)?;
+			Ok(format_element(&element, options))
+		}
+		Language::Unknown => Err(FormatError::UnsupportedLanguage),
+	}
+}
+
+/// Formats the statements of `source` that are covered by `range`, for example the code selected
+/// in an editor, as if it was the content of the file at `rome_path`.
+///
+/// Returns the edit that replaces the formatted statements with their formatted code, see
+/// [Formatter::format_range]. Only JavaScript and TypeScript support range formatting.
+pub fn format_range(
+	rome_path: &RomePath,
+	source: &str,
+	mut options: FormatOptions,
+	range: TextRange,
+) -> FormatResult<TextEdit> {
+	if options.line_ending == LineEnding::Auto {
+		options.line_ending = LineEnding::detect(source);
+	}
+
+	match formatted_language(rome_path)? {
+		Language::Js => {
+			let root = parse_js(source, source_type(rome_path, &options), &options)?;
+			Formatter::new(options).format_range(&root, range)
+		}
+		Language::Ts => {
+			let root = parse_ts(source, &options)?;
+			Formatter::new(options).format_range(&root, range)
+		}
+		Language::Json | Language::Unknown => Err(FormatError::UnsupportedLanguage),
+	}
+}
+
+/// Returns the language of the file at `rome_path`, if its handler can format it
+fn formatted_language(rome_path: &RomePath) -> FormatResult<Language> {
+	match rome_path.get_handler() {
+		Some(handler) if handler.capabilities().format => Ok(handler.language()),
+		Some(_) => Err(FormatError::CapabilityDisabled),
+		None => Err(FormatError::UnsupportedLanguage),
+	}
+}
+
+/// Returns how the JavaScript file at `rome_path` is parsed: its extension tells if it's a module
+/// or a script, and the options tell it for the `.js` files
+fn source_type(rome_path: &RomePath, options: &FormatOptions) -> SourceType {
+	match rome_path
+		.extension()
+		.and_then(|extension| extension.to_str())
+	{
+		Some("mjs") => SourceType::Module,
+		Some("cjs") => SourceType::Script,
+		_ => options.source_type,
+	}
+}
+
+/// Parses JavaScript code, failing if it contains syntax errors unless the options allow to format them
+fn parse_js(
+	source: &str,
+	source_type: SourceType,
+	options: &FormatOptions,
+) -> FormatResult<SyntaxNode> {
+	let syntax = match source_type {
+		SourceType::Script | SourceType::Auto => Syntax::default().script().class_fields(),
+		SourceType::Module => Syntax::default().module(),
+	};
+
+	let mut parsed_result = parse_with_syntax(source, 0, syntax);
+
+	// The code is parsed again only if it's a module, the scripts are parsed once
+	if source_type == SourceType::Auto && has_module_declarations(&parsed_result.syntax()) {
+		parsed_result = parse_with_syntax(source, 0, Syntax::default().module());
+	}
+	if !parsed_result.errors().is_empty() && !options.format_with_errors {
+		return Err(FormatError::SyntaxError(parsed_result.errors().to_vec()));
+	}
+
+	Ok(parsed_result.syntax())
+}
+
+/// Whether the statements of the script contain `import` or `export` declarations. The parser
+/// reports them as errors outside of modules, their nodes are [SyntaxKind::ERROR] nodes
+fn has_module_declarations(root: &SyntaxNode) -> bool {
+	root.children()
+		.flat_map(|list| list.children())
+		.any(|statement| {
+			statement.kind() == SyntaxKind::ERROR
+				&& matches!(
+					statement.first_token().map(|token| token.kind()),
+					Some(T![import] | T![export])
+				)
+		})
+}
+
+/// Parses TypeScript code, failing if it contains syntax errors unless the options allow to format them.
+///
+/// Declaration files (`.d.ts`) are parsed the same way, their ambient declarations are regular
+/// TypeScript statements.
+fn parse_ts(source: &str, options: &FormatOptions) -> FormatResult<SyntaxNode> {
+	let parsed_result = parse_with_syntax(source, 0, Syntax::default().typescript());
+
+	if !parsed_result.errors().is_empty() && !options.format_with_errors {
+		return Err(FormatError::SyntaxError(parsed_result.errors().to_vec()));
+	}
+
+	Ok(parsed_result.syntax())
 }
 
 pub fn format_file_and_save(rome_path: &mut RomePath, options: FormatOptions) {
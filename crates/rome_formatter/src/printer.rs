@@ -1,10 +1,15 @@
 use crate::format_element::{ConditionalGroupContent, Group, GroupPrintMode, LineMode};
-use crate::{FormatElement, FormatOptions, Formatted, IndentStyle};
+use crate::{hard_line_break, FormatElement, FormatOptions, Formatted, IndentStyle, SourceMapping};
+use rome_rowan::TextSize;
+use std::io;
+use std::str::FromStr;
+use unicode_width::UnicodeWidthChar;
 
 /// Options that affect how the [Printer] prints the format tokens
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct PrinterOptions {
-	/// Width of a single tab character (does it equal 2, 4, ... spaces?)
+	/// Width of a single tab character (does it equal 2, 4, ... spaces?), in the indention
+	/// as well as inside tokens
 	pub tab_width: u8,
 
 	/// What's the max width of a line. Defaults to 80
@@ -19,29 +24,38 @@ pub struct PrinterOptions {
 	/// * Tab: Value is '\t'
 	/// * Spaces: String containing the number of spaces per indention level, e.g. "  " for using two spaces
 	pub indent_string: String,
+
+	/// Measures the width of the characters other than tabs, which are [PrinterOptions::tab_width]
+	/// wide. The line width and the fits checks of the groups rely on it.
+	/// Defaults to [unicode_char_width]
+	pub char_width: fn(char) -> usize,
+}
+
+/// Returns the Unicode display width of a character: two columns for most CJK characters and
+/// emoji, none for combining and control characters, and one column for the other characters
+pub fn unicode_char_width(char: char) -> usize {
+	char.width().unwrap_or(0)
 }
 
 impl From<FormatOptions> for PrinterOptions {
 	fn from(options: FormatOptions) -> Self {
-		let indent_string: String;
-		let tab_width = 2;
-
-		match options.indent_style {
-			IndentStyle::Tab => indent_string = String::from("\t"),
-			IndentStyle::Space(width) => indent_string = " ".repeat(width as usize),
+		// A tab in a token is as wide as an indention level, the default width is used for tabs
+		let (indent_string, tab_width) = match options.indent_style {
+			IndentStyle::Tab => (String::from("\t"), PrinterOptions::default().tab_width),
+			IndentStyle::Space(width) => (" ".repeat(width as usize), width),
 		};
 
 		PrinterOptions {
 			indent_string,
 			tab_width,
 			print_width: options.line_width,
+			line_ending: options.line_ending,
 			..PrinterOptions::default()
 		}
 	}
 }
 
-#[allow(dead_code)]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum LineEnding {
 	///  Line Feed only (\n), common on Linux and macOS as well as inside git repos
 	LineFeed,
@@ -51,17 +65,49 @@ pub enum LineEnding {
 
 	/// Carriage Return character only (\r), used very rarely
 	CarriageReturn,
+
+	/// Uses the same line ending as the source code, see [LineEnding::detect].
+	///
+	/// It's resolved by [crate::format_source], the printer falls back to a line feed
+	/// if it isn't.
+	Auto,
 }
 
 impl LineEnding {
 	#[inline]
 	pub const fn as_str(&self) -> &'static str {
 		match self {
-			LineEnding::LineFeed => "\n",
+			LineEnding::LineFeed | LineEnding::Auto => "\n",
 			LineEnding::CarriageReturnLineFeed => "\r\n",
 			LineEnding::CarriageReturn => "\r",
 		}
 	}
+
+	/// Returns the line ending of the first line of `source`, or [LineEnding::LineFeed] if `source`
+	/// is a single line
+	pub fn detect(source: &str) -> LineEnding {
+		match source.find(&['\n', '\r'][..]) {
+			Some(index) if source[index..].starts_with("\r\n") => {
+				LineEnding::CarriageReturnLineFeed
+			}
+			Some(index) if source[index..].starts_with('\r') => LineEnding::CarriageReturn,
+			_ => LineEnding::LineFeed,
+		}
+	}
+}
+
+impl FromStr for LineEnding {
+	type Err = &'static str;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"lf" => Ok(Self::LineFeed),
+			"crlf" => Ok(Self::CarriageReturnLineFeed),
+			"cr" => Ok(Self::CarriageReturn),
+			"auto" => Ok(Self::Auto),
+			_ => Err("Value not supported for LineEnding"),
+		}
+	}
 }
 
 impl Default for PrinterOptions {
@@ -71,6 +117,7 @@ impl Default for PrinterOptions {
 			print_width: 80,
 			indent_string: String::from("\t"),
 			line_ending: LineEnding::LineFeed,
+			char_width: unicode_char_width,
 		}
 	}
 }
@@ -79,6 +126,9 @@ impl Default for PrinterOptions {
 /// explicit line breaks or would otherwise exceed the specified line width.
 struct LineBreakRequiredError;
 
+/// The size of the chunks in which [Printer::print_to] writes the printed code
+const OUTPUT_CHUNK_SIZE: usize = 8 * 1024;
+
 /// Prints the format elements into a string
 #[derive(Debug, Clone, Default)]
 pub struct Printer {
@@ -95,16 +145,76 @@ impl Printer {
 	}
 
 	/// Prints the passed in element as well as all its content
-	pub fn print(mut self, element: &FormatElement) -> Formatted {
-		let mut queue = ElementCallQueue::new();
+	pub fn print(self, element: &FormatElement) -> Formatted {
+		let mut code = Vec::new();
+		let mappings = self
+			.print_to(element, &mut code)
+			.expect("writing to a vector can't fail");
+		let code = String::from_utf8(code).expect("the printed code is valid UTF-8");
+
+		Formatted::new(&code).with_mappings(mappings)
+	}
 
+	/// Prints the passed in element as well as all its content to `writer` and returns the
+	/// mappings of its source markers, see [Formatted::mappings].
+	///
+	/// The printed code is written to `writer` whenever at least 8 KiB are buffered, and once more
+	/// at the end. Chunks don't end at line boundaries. This only bounds the printed code held in
+	/// memory: `element` is the whole document, and it must be built before printing starts.
+	pub fn print_to<W: io::Write>(
+		mut self,
+		element: &FormatElement,
+		mut writer: W,
+	) -> io::Result<Vec<SourceMapping>> {
+		let mut queue = ElementCallQueue::new();
 		queue.enqueue(PrintElementCall::new(element, PrintElementArgs::default()));
 
-		while let Some(print_element_call) = queue.dequeue() {
-			queue.extend(self.print_element(print_element_call.element, print_element_call.args));
+		while let Some(call) = queue.dequeue() {
+			queue.extend(self.print_call(call));
+
+			// Nothing that has been printed so far can be reverted
+			if self.state.buffer.len() >= OUTPUT_CHUNK_SIZE {
+				self.write_buffer(&mut writer)?;
+			}
 		}
 
-		Formatted::new(self.state.buffer.as_str())
+		// A line comment at the end of the document that isn't followed by a line break
+		self.flush_line_suffixes();
+		// Markers at the end of the document, e.g. the end of the last node
+		self.flush_source_markers();
+
+		self.write_buffer(&mut writer)?;
+		writer.flush()?;
+
+		Ok(self.state.mappings)
+	}
+
+	/// Writes the printed code to `writer` and clears the buffer
+	fn write_buffer<W: io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+		writer.write_all(self.state.buffer.as_bytes())?;
+		self.state.written += self.state.buffer.len();
+		self.state.buffer.clear();
+
+		Ok(())
+	}
+
+	/// Prints the element and all its content
+	fn print_all(&mut self, element: &FormatElement, args: PrintElementArgs) {
+		let mut queue = ElementCallQueue::new();
+
+		queue.enqueue(PrintElementCall::new(element, args));
+
+		while let Some(call) = queue.dequeue() {
+			queue.extend(self.print_call(call));
+		}
+	}
+
+	/// Prints the element of the call, or the separator of a fill, and returns the elements to queue
+	fn print_call<'a>(&mut self, call: PrintElementCall<'a>) -> Vec<PrintElementCall<'a>> {
+		match call.fill_element {
+			Some(fill_element) => self.print_fill_separator(call.element, fill_element, call.args),
+			None => self.print_element(call.element, call.args),
+		}
 	}
 
 	/// Prints a single element and returns the elements to queue (that should be printed next).
@@ -120,6 +230,12 @@ impl Printer {
 			}
 			FormatElement::Empty => vec![],
 			FormatElement::Token(token) => {
+				// Spaces at the start of a line, e.g. after the line break forced by a comment, are
+				// replaced by the indention
+				if self.state.line_width == 0 {
+					self.state.pending_spaces = 0;
+				}
+
 				// Print pending indention
 				if self.state.pending_indent > 0 {
 					self.print_str(
@@ -137,7 +253,28 @@ impl Printer {
 					self.state.pending_spaces = 0;
 				}
 
-				self.print_str(token);
+				self.flush_source_markers();
+
+				// A token printed verbatim may span multiple lines, the line suffixes are
+				// printed before its first line break
+				match token.find(&['\n', '\r'][..]) {
+					Some(index) if !self.state.line_suffixes.is_empty() => {
+						self.print_str(&token[..index]);
+						self.flush_line_suffixes();
+						self.print_str(&token[index..]);
+					}
+					_ => self.print_str(token),
+				}
+				vec![]
+			}
+
+			FormatElement::SourceMarker(position) => {
+				// The content that follows the marker is printed after the pending indention and spaces
+				if self.state.line_width == 0 || self.state.pending_spaces > 0 {
+					self.state.pending_source_markers.push(*position);
+				} else {
+					self.push_mapping(*position);
+				}
 				vec![]
 			}
 
@@ -156,6 +293,59 @@ impl Printer {
 				.map(|t| PrintElementCall::new(t, args.clone()))
 				.collect(),
 
+			FormatElement::Fill(fill) => {
+				let mut calls = Vec::with_capacity(fill.list.len() * 2);
+
+				for (index, element) in fill.list.iter().enumerate() {
+					if index > 0 {
+						calls.push(PrintElementCall::fill_separator(
+							&fill.separator,
+							element,
+							args.clone(),
+						));
+					}
+					calls.push(PrintElementCall::new(element, args.clone()));
+				}
+
+				calls
+			}
+
+			FormatElement::BestFitting(best_fitting) => {
+				let (most_expanded, variants) = match best_fitting.variants.split_last() {
+					Some(split) => split,
+					None => return vec![],
+				};
+
+				if let Some(most_flat) = variants.first() {
+					if self.try_print_flat(most_flat, args.clone()).is_ok() {
+						return vec![];
+					}
+				}
+
+				// The most flat variant may still fit up to its first line break, for example a call
+				// with a function as last argument
+				let variant = variants
+					.iter()
+					.find(|variant| self.fits_first_line(variant, args.clone()))
+					.unwrap_or(most_expanded);
+
+				vec![PrintElementCall::new(variant, args)]
+			}
+
+			FormatElement::LineSuffix(line_suffix) => {
+				self.state
+					.line_suffixes
+					.push(((*line_suffix.content).clone(), args));
+				vec![]
+			}
+
+			FormatElement::LineSuffixBoundary => {
+				if !self.state.line_suffixes.is_empty() {
+					self.print_all(&hard_line_break(), args);
+				}
+				vec![]
+			}
+
 			FormatElement::Indent(indent) => {
 				vec![PrintElementCall::new(
 					&indent.content,
@@ -177,8 +367,19 @@ impl Printer {
 				vec![]
 			}
 
-			FormatElement::Line { .. } => {
-				self.print_str("\n");
+			FormatElement::Line(line) => {
+				self.flush_line_suffixes();
+
+				// Nothing has been printed on the current line yet, another line break would create
+				// an empty line. Only updates the indention, e.g. for a line break forced by a comment
+				// followed by an indented line break.
+				if self.state.line_width > 0 {
+					self.print_str("\n");
+				}
+
+				if line.mode == LineMode::Empty && !self.is_after_empty_line() {
+					self.print_str("\n");
+				}
 				self.state.pending_spaces = 0;
 				self.state.pending_indent = args.indent;
 				vec![]
@@ -186,6 +387,62 @@ impl Printer {
 		}
 	}
 
+	/// Prints the separator between two elements of a fill flat if the element that follows it
+	/// fits on the rest of the line, otherwise returns the call that prints it broken
+	fn print_fill_separator<'a>(
+		&mut self,
+		separator: &'a FormatElement,
+		fill_element: &FormatElement,
+		args: PrintElementArgs,
+	) -> Vec<PrintElementCall<'a>> {
+		// Measures the separator and the element printed flat, the element is then printed as
+		// usual because its content that is only printed if the enclosing group breaks,
+		// e.g. a trailing comma, must be kept. The element is always on the next line if the
+		// previous one ends with a line suffix, e.g. a line comment
+		let snapshot = self.state.snapshot();
+		let fits = self.state.line_suffixes.is_empty()
+			&& self
+				.try_print_flat(separator, args.clone())
+				.and_then(|_| self.try_print_flat(fill_element, args.clone()))
+				.is_ok();
+		self.state.restore(snapshot);
+
+		if fits && self.try_print_flat(separator, args.clone()).is_ok() {
+			vec![]
+		} else {
+			vec![PrintElementCall::new(separator, args)]
+		}
+	}
+
+	/// Returns `true` if the element printed as usual doesn't exceed the print width on the
+	/// current line, measuring it up to its first line break. Reverts the state changes.
+	fn fits_first_line(&mut self, element: &FormatElement, args: PrintElementArgs) -> bool {
+		let snapshot = self.state.snapshot();
+		let line = self.state.generated_line;
+
+		let mut queue = ElementCallQueue::new();
+		queue.enqueue(PrintElementCall::new(element, args));
+
+		// Stops at the end of the first line, the content that follows doesn't matter
+		while let Some(call) = queue.dequeue() {
+			if self.state.generated_line != line
+				|| self.state.line_width > self.options.print_width as usize
+			{
+				break;
+			}
+
+			queue.extend(self.print_call(call));
+		}
+
+		let printed = &self.state.buffer[snapshot.buffer_position..];
+		let first_line = printed.split(&['\n', '\r'][..]).next().unwrap_or_default();
+		let fits =
+			snapshot.line_width + self.text_width(first_line) <= self.options.print_width as usize;
+
+		self.state.restore(snapshot);
+		fits
+	}
+
 	/// Tries to print an element without any line breaks. Reverts any made `state` changes (by this function)
 	/// and returns with a [LineBreakRequiredError] if the `element` contains any hard line breaks
 	/// or printing the group exceeds the configured maximal print width.
@@ -195,11 +452,21 @@ impl Printer {
 		args: PrintElementArgs,
 	) -> Result<(), LineBreakRequiredError> {
 		let snapshot = self.state.snapshot();
+		let line_suffixes = self.state.line_suffixes.len();
 
 		let mut queue = ElementCallQueue::new();
 		queue.enqueue(PrintElementCall::new(element, args));
 
 		while let Some(call) = queue.dequeue() {
+			// The content that follows a line suffix of the element, e.g. a trailing line comment,
+			// must be printed on the next line
+			if matches!(call.element, FormatElement::Token(_))
+				&& self.state.line_suffixes.len() > line_suffixes
+			{
+				self.state.restore(snapshot);
+				return Err(LineBreakRequiredError);
+			}
+
 			match self.try_print_flat_element(call.element, call.args) {
 				Ok(to_queue) => queue.extend(to_queue),
 				Err(err) => {
@@ -244,7 +511,7 @@ impl Printer {
 					}
 					// We want a flat structure, so omit soft line wraps
 					LineMode::Soft => vec![],
-					LineMode::Hard => return Err(LineBreakRequiredError),
+					LineMode::Hard | LineMode::Empty => return Err(LineBreakRequiredError),
 				}
 			}
 
@@ -257,26 +524,107 @@ impl Printer {
 				content,
 			}) => vec![PrintElementCall::new(content, args)],
 
+			FormatElement::Fill(fill) => {
+				let mut calls = Vec::with_capacity(fill.list.len() * 2);
+
+				for (index, element) in fill.list.iter().enumerate() {
+					if index > 0 {
+						calls.push(PrintElementCall::new(&fill.separator, args.clone()));
+					}
+					calls.push(PrintElementCall::new(element, args.clone()));
+				}
+
+				calls
+			}
+
+			// The first variant is the most flat layout
+			FormatElement::BestFitting(best_fitting) => {
+				vec![PrintElementCall::new(&best_fitting.variants[0], args)]
+			}
+
 			// Omit if there's no flat_contents
 			FormatElement::ConditionalGroupContent(ConditionalGroupContent {
 				mode: GroupPrintMode::Multiline,
 				..
 			}) => vec![],
 
+			// Prints a line break if there are pending line suffixes
+			FormatElement::LineSuffixBoundary if !self.state.line_suffixes.is_empty() => {
+				return Err(LineBreakRequiredError)
+			}
+
 			FormatElement::Empty
 			| FormatElement::Space
 			| FormatElement::Indent { .. }
-			| FormatElement::List { .. } => self.print_element(element, args),
+			| FormatElement::List { .. }
+			| FormatElement::LineSuffix(_)
+			| FormatElement::LineSuffixBoundary
+			| FormatElement::SourceMarker(_) => self.print_element(element, args),
 		};
 
 		Ok(next_calls)
 	}
 
+	/// Prints the pending line suffixes, before a line break
+	fn flush_line_suffixes(&mut self) {
+		let line_suffixes = std::mem::take(&mut self.state.line_suffixes);
+		for (content, args) in line_suffixes {
+			self.print_all(&content, args);
+		}
+	}
+
+	/// Maps the positions of the pending source markers to the current position in the output
+	fn flush_source_markers(&mut self) {
+		let markers = std::mem::take(&mut self.state.pending_source_markers);
+		for position in markers {
+			self.push_mapping(position);
+		}
+	}
+
+	fn push_mapping(&mut self, source: TextSize) {
+		let mapping = SourceMapping {
+			source,
+			generated: TextSize::from((self.state.written + self.state.buffer.len()) as u32),
+		};
+
+		if self.state.mappings.last() != Some(&mapping) {
+			self.state.mappings.push(mapping);
+		}
+	}
+
+	/// Returns `true` at the start of the document or if the previous line is empty
+	fn is_after_empty_line(&self) -> bool {
+		self.state.generated_index == 0 || self.state.trailing_line_endings > 1
+	}
+
+	/// Returns the width of a character printed on a line, the tab width for a tab, see
+	/// [PrinterOptions::char_width]
+	fn char_width(&self, char: char) -> usize {
+		if char == '\t' {
+			self.options.tab_width as usize
+		} else {
+			(self.options.char_width)(char)
+		}
+	}
+
+	/// Returns the width of a text that doesn't contain line breaks, see [Printer::char_width]
+	fn text_width(&self, text: &str) -> usize {
+		text.chars().map(|char| self.char_width(char)).sum()
+	}
+
 	fn print_str(&mut self, content: &str) {
 		self.state.buffer.reserve(content.len());
 
-		for char in content.chars() {
-			if char == '\n' {
+		let mut chars = content.chars().peekable();
+
+		while let Some(char) = chars.next() {
+			// Tokens printed verbatim may contain the line endings of the source code,
+			// normalize them so that they use the configured line ending
+			if char == '\r' && chars.peek() == Some(&'\n') {
+				continue;
+			}
+
+			if char == '\n' || char == '\r' {
 				for char in self.options.line_ending.as_str().chars() {
 					self.state.generated_index += 1;
 					self.state.buffer.push(char);
@@ -285,18 +633,14 @@ impl Printer {
 				self.state.generated_line += 1;
 				self.state.generated_column = 0;
 				self.state.line_width = 0;
+				self.state.trailing_line_endings += 1;
 			} else {
 				self.state.buffer.push(char);
 				self.state.generated_index += 1;
 				self.state.generated_column += 1;
 
-				let char_width = if char == '\t' {
-					self.options.tab_width as usize
-				} else {
-					1
-				};
-
-				self.state.line_width += char_width;
+				self.state.line_width += self.char_width(char);
+				self.state.trailing_line_endings = 0;
 			}
 		}
 	}
@@ -307,18 +651,24 @@ impl Printer {
 /// position the printer currently is.
 #[derive(Default, Debug, Clone)]
 struct PrinterState {
+	/// The printed code that hasn't been written yet, see [Printer::print_to]
 	buffer: String,
+	/// The length of the code that has been written
+	written: usize,
+	/// The number of line endings at the end of the printed code
+	trailing_line_endings: usize,
 	pending_indent: u16,
 	pending_spaces: u16,
 	generated_index: usize,
 	generated_line: usize,
 	generated_column: usize,
 	line_width: usize,
-	// mappings: Mapping[];
-	// We'll need to clone the line suffixes elements into the state.
-	// I guess that's fine. They're only used for comments and should, therefore, be very limited
-	// in size.
-	// lineSuffixes: [FormatElement, PrintElementArgs][];
+	/// The positions of the source markers whose content hasn't been printed yet
+	pending_source_markers: Vec<TextSize>,
+	mappings: Vec<SourceMapping>,
+	/// The content of the line suffixes printed before the next line break. They're cloned into
+	/// the state, which is fine because they're only used for comments and are therefore small.
+	line_suffixes: Vec<(FormatElement, PrintElementArgs)>,
 }
 
 impl PrinterState {
@@ -331,7 +681,11 @@ impl PrinterState {
 			generated_line: self.generated_line,
 			generated_column: self.generated_column,
 			line_width: self.line_width,
+			trailing_line_endings: self.trailing_line_endings,
 			buffer_position: self.buffer.len(),
+			pending_source_markers: self.pending_source_markers.clone(),
+			mappings_len: self.mappings.len(),
+			line_suffixes: self.line_suffixes.clone(),
 		}
 	}
 
@@ -343,7 +697,11 @@ impl PrinterState {
 		self.generated_column = snapshot.generated_column;
 		self.generated_line = snapshot.generated_line;
 		self.line_width = snapshot.line_width;
+		self.trailing_line_endings = snapshot.trailing_line_endings;
 		self.buffer.truncate(snapshot.buffer_position);
+		self.pending_source_markers = snapshot.pending_source_markers;
+		self.mappings.truncate(snapshot.mappings_len);
+		self.line_suffixes = snapshot.line_suffixes;
 	}
 }
 
@@ -355,7 +713,11 @@ struct PrinterStateSnapshot {
 	generated_column: usize,
 	generated_line: usize,
 	line_width: usize,
+	trailing_line_endings: usize,
 	buffer_position: usize,
+	pending_source_markers: Vec<TextSize>,
+	mappings_len: usize,
+	line_suffixes: Vec<(FormatElement, PrintElementArgs)>,
 }
 
 /// Stores arguments passed to `print_element` call, holding the state specific to printing an element.
@@ -395,11 +757,30 @@ impl PrintElementArgs {
 struct PrintElementCall<'element> {
 	element: &'element FormatElement,
 	args: PrintElementArgs,
+	/// The element that follows the separator of a fill: the separator is printed flat if the
+	/// element fits on the rest of the line
+	fill_element: Option<&'element FormatElement>,
 }
 
 impl<'element> PrintElementCall<'element> {
 	pub fn new(element: &'element FormatElement, args: PrintElementArgs) -> Self {
-		Self { element, args }
+		Self {
+			element,
+			args,
+			fill_element: None,
+		}
+	}
+
+	pub fn fill_separator(
+		separator: &'element FormatElement,
+		fill_element: &'element FormatElement,
+		args: PrintElementArgs,
+	) -> Self {
+		Self {
+			element: separator,
+			args,
+			fill_element: Some(fill_element),
+		}
 	}
 }
 
@@ -439,8 +820,10 @@ mod tests {
 	use crate::format_element::join_elements;
 	use crate::printer::{LineEnding, Printer, PrinterOptions};
 	use crate::{
-		block_indent, format_elements, group_elements, hard_line_break, if_group_breaks,
-		soft_indent, soft_line_break, soft_line_break_or_space, token, FormatElement, Formatted,
+		best_fitting, block_indent, fill_elements, format_elements, group_elements,
+		hard_line_break, if_group_breaks, line_suffix, soft_indent, soft_line_break,
+		soft_line_break_or_space, source_marker, space_token, token, FormatElement, FormatOptions,
+		Formatted, IndentStyle, SourceMapping,
 	};
 
 	/// Prints the given element with the default printer options
@@ -511,6 +894,48 @@ two lines`,
 		)
 	}
 
+	#[test]
+	fn it_does_not_print_a_line_break_at_the_start_of_a_line() {
+		let result = print_element(format_elements![
+			token("a"),
+			hard_line_break(),
+			hard_line_break(),
+			space_token(),
+			token("b"),
+			block_indent(token("c")),
+		]);
+
+		assert_eq!("a\nb\n  c\n", result.code())
+	}
+
+	#[test]
+	fn it_maps_the_source_markers_to_the_printed_content() {
+		let result = print_element(format_elements![
+			token("{"),
+			block_indent(format_elements![
+				source_marker(2.into()),
+				token("a"),
+				source_marker(3.into()),
+				space_token(),
+				source_marker(4.into()),
+				token("b"),
+			]),
+			token("}"),
+		]);
+
+		let mapping = |source: u32, generated: u32| SourceMapping {
+			source: source.into(),
+			generated: generated.into(),
+		};
+
+		assert_eq!("{\n  a b\n}", result.code());
+		// The markers before the indention or a space map to the content that follows them
+		assert_eq!(
+			&[mapping(2, 4), mapping(3, 5), mapping(4, 6)],
+			result.mappings()
+		);
+	}
+
 	#[test]
 	fn it_converts_line_endings_in_strings() {
 		let options = PrinterOptions {
@@ -533,6 +958,31 @@ two lines`,
 		);
 	}
 
+	#[test]
+	fn it_normalizes_the_line_endings_of_the_source() {
+		let options = PrinterOptions {
+			line_ending: LineEnding::LineFeed,
+			..PrinterOptions::default()
+		};
+
+		let program = format_elements![token("`a\r\nb\rc`"), hard_line_break()];
+
+		let result = Printer::new(options).print(&program);
+
+		assert_eq!("`a\nb\nc`\n", result.code());
+	}
+
+	#[test]
+	fn it_detects_the_line_ending() {
+		assert_eq!(
+			LineEnding::detect("a\r\nb\n"),
+			LineEnding::CarriageReturnLineFeed
+		);
+		assert_eq!(LineEnding::detect("a\rb"), LineEnding::CarriageReturn);
+		assert_eq!(LineEnding::detect("a\nb\r\n"), LineEnding::LineFeed);
+		assert_eq!(LineEnding::detect("a"), LineEnding::LineFeed);
+	}
+
 	#[test]
 	fn it_breaks_parent_groups_if_they_dont_fit_on_a_single_line() {
 		let result = print_element(create_array_element(vec![
@@ -580,6 +1030,196 @@ two lines`,
 		assert_eq!("[\n\t'a',\n\t\'b',\n\t\'c',\n\t'd',\n]", result.code());
 	}
 
+	#[test]
+	fn it_fills_the_lines_with_as_many_elements_as_fit() {
+		let printer = Printer::new(PrinterOptions {
+			print_width: 10,
+			..PrinterOptions::default()
+		});
+
+		let numbers = (1..=7).map(|number| token(format!("{},", number * 11)));
+		let element = format_elements![
+			token("["),
+			block_indent(fill_elements(soft_line_break_or_space(), numbers)),
+			token("]"),
+		];
+
+		assert_eq!(
+			"[\n\t11, 22,\n\t33, 44,\n\t55, 66,\n\t77,\n]",
+			printer.print(&element).code()
+		);
+	}
+
+	#[test]
+	fn it_keeps_the_content_of_broken_groups_in_fill_elements() {
+		let result = print_element(group_elements(format_elements![
+			token("["),
+			soft_indent(fill_elements(
+				soft_line_break_or_space(),
+				vec![
+					token("\"0123456789\","),
+					token("\"0123456789\","),
+					token("\"0123456789\","),
+					token("\"0123456789\","),
+					token("\"0123456789\","),
+					format_elements![token("\"0123456789\""), if_group_breaks(token(","))],
+				]
+			)),
+			token("]"),
+		]));
+
+		assert_eq!(
+			r#"[
+  "0123456789", "0123456789", "0123456789", "0123456789", "0123456789",
+  "0123456789",
+]"#,
+			result.code()
+		);
+	}
+
+	#[test]
+	fn it_prints_the_first_best_fitting_variant_that_fits() {
+		let print = |print_width: u16| {
+			let printer = Printer::new(PrinterOptions {
+				print_width,
+				indent_string: String::from("  "),
+				..PrinterOptions::default()
+			});
+
+			let body = block_indent(token("body();"));
+			let element = best_fitting(vec![
+				format_elements![token("f(() => {"), body.clone(), token("})")],
+				format_elements![
+					token("f("),
+					block_indent(format_elements![token("() => {"), body, token("}")]),
+					token(")")
+				],
+			]);
+
+			printer.print(&element).code().to_string()
+		};
+
+		// The first variant contains a line break, it never fits flat
+		assert_eq!("f(() => {\n  body();\n})", print(9));
+		assert_eq!("f(\n  () => {\n    body();\n  }\n)", print(8));
+	}
+
+	#[test]
+	fn it_prints_the_line_suffixes_before_the_next_line_break() {
+		let comment = |text: &str| line_suffix(format_elements![space_token(), token(text)]);
+
+		let result = print_element(format_elements![
+			create_array_element(vec![
+				format_elements![token("a"), comment("// a")],
+				token("b"),
+			]),
+			create_array_element(vec![token("c"), token("d")]),
+			comment("// end"),
+			token(";"),
+		]);
+
+		// The group breaks because content follows the line suffix, the suffix at its end doesn't
+		// prevent the second group from fitting
+		assert_eq!("[\n  a, // a\n  b,\n][c, d]; // end", result.code());
+	}
+
+	#[test]
+	fn it_measures_the_display_width_of_the_tokens() {
+		let print = |items: Vec<FormatElement>| {
+			let printer = Printer::new(PrinterOptions {
+				print_width: 12,
+				tab_width: 4,
+				..PrinterOptions::default()
+			});
+
+			printer
+				.print(&create_array_element(items))
+				.code()
+				.to_string()
+		};
+
+		// The wide characters take two columns: `["日本", "語"]` is 14 columns wide
+		assert_eq!(
+			"[\n\t\"日本\",\n\t\"語\",\n]",
+			print(vec![token("\"日本\""), token("\"語\"")])
+		);
+		// The combining accents take no column: `["éé", "e"]` is 11 columns wide
+		assert_eq!(
+			"[\"e\u{301}e\u{301}\", \"e\"]",
+			print(vec![token("\"e\u{301}e\u{301}\""), token("\"e\"")])
+		);
+		// A tab takes the tab width: `["\t", "a"]` is 13 columns wide
+		assert_eq!(
+			"[\n\t\"\t\",\n\t\"a\",\n]",
+			print(vec![token("\"\t\""), token("\"a\"")])
+		);
+	}
+
+	#[test]
+	fn it_measures_the_characters_with_the_width_function() {
+		// Counts every character as one column, like an editor that doesn't support wide characters
+		let printer = Printer::new(PrinterOptions {
+			print_width: 12,
+			char_width: |_| 1,
+			..PrinterOptions::default()
+		});
+
+		// `["日本", "語"]` is 12 columns wide
+		assert_eq!(
+			"[\"日本\", \"語\"]",
+			printer
+				.print(&create_array_element(vec![
+					token("\"日本\""),
+					token("\"語\"")
+				]))
+				.code()
+		);
+	}
+
+	#[test]
+	fn it_uses_the_indent_size_as_tab_width() {
+		let options = PrinterOptions::from(FormatOptions::new(IndentStyle::Space(4)));
+		assert_eq!(options.tab_width, 4);
+
+		let options = PrinterOptions::from(FormatOptions::new(IndentStyle::Tab));
+		assert_eq!(options.tab_width, PrinterOptions::default().tab_width);
+	}
+
+	#[test]
+	fn it_writes_the_code_while_printing() {
+		/// Records the chunks written by the printer
+		#[derive(Default)]
+		struct Chunks(Vec<Vec<u8>>);
+
+		impl std::io::Write for Chunks {
+			fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+				self.0.push(buf.to_vec());
+				Ok(buf.len())
+			}
+
+			fn flush(&mut self) -> std::io::Result<()> {
+				Ok(())
+			}
+		}
+
+		let statements = (0..2000).map(|index| {
+			format_elements![
+				source_marker((index as u32).into()),
+				create_array_element(vec![token(format!("{}", index)), token("\"b\"")]),
+				token(";"),
+			]
+		});
+		let element = join_elements(hard_line_break(), statements);
+
+		let mut chunks = Chunks::default();
+		let mappings = Printer::default().print_to(&element, &mut chunks).unwrap();
+		let formatted = Printer::default().print(&element);
+
+		assert!(chunks.0.len() > 1);
+		assert_eq!(chunks.0.concat(), formatted.code().as_bytes());
+		assert_eq!(mappings, formatted.mappings());
+	}
+
 	fn create_array_element(items: Vec<FormatElement>) -> FormatElement {
 		let separator = format_elements![token(","), soft_line_break_or_space(),];
 
@@ -0,0 +1,237 @@
+//! Generation of the [Source Map v3](https://sourcemaps.info/spec.html) of the formatted code.
+//!
+//! The printer maps the position of every [crate::source_marker] to the position where the
+//! content that follows the marker is printed. The mappings are then encoded in the format
+//! expected by browsers and bundlers so that, for example, stack traces point to the right
+//! location even after the code has been formatted.
+use rome_rowan::TextSize;
+
+/// Characters used by the base 64 VLQ encoding of the mappings
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Maps a position in the source code to the position where it's printed in the formatted code.
+///
+/// Both positions are byte offsets.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct SourceMapping {
+	pub source: TextSize,
+	pub generated: TextSize,
+}
+
+/// Source map (version 3) from the formatted code to the source code
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SourceMap {
+	/// The name of the source file, as it appears in the `sources` field
+	source_name: String,
+	/// The base 64 VLQ encoded mappings
+	mappings: String,
+}
+
+impl SourceMap {
+	/// Creates the source map of the `generated` code, formatted from `source`
+	pub(crate) fn new(
+		source_name: &str,
+		source: &str,
+		generated: &str,
+		mappings: &[SourceMapping],
+	) -> Self {
+		let source_lines = LineIndex::new(source);
+		let generated_lines = LineIndex::new(generated);
+
+		let mut encoded = String::new();
+		let mut generated_line = 0;
+		let mut previous_generated_column = 0;
+		let mut previous_source_line = 0;
+		let mut previous_source_column = 0;
+		let mut is_first_segment_of_line = true;
+
+		for mapping in mappings {
+			let (line, column) = generated_lines.line_column(mapping.generated);
+			let (source_line, source_column) = source_lines.line_column(mapping.source);
+
+			while generated_line < line {
+				encoded.push(';');
+				generated_line += 1;
+				previous_generated_column = 0;
+				is_first_segment_of_line = true;
+			}
+
+			if !is_first_segment_of_line {
+				encoded.push(',');
+			}
+			is_first_segment_of_line = false;
+
+			encode_vlq(&mut encoded, column - previous_generated_column);
+			// There's a single source file, the index in `sources` is always 0
+			encode_vlq(&mut encoded, 0);
+			encode_vlq(&mut encoded, source_line - previous_source_line);
+			encode_vlq(&mut encoded, source_column - previous_source_column);
+
+			previous_generated_column = column;
+			previous_source_line = source_line;
+			previous_source_column = source_column;
+		}
+
+		Self {
+			source_name: String::from(source_name),
+			mappings: encoded,
+		}
+	}
+
+	/// The base 64 VLQ encoded mappings, the `mappings` field of the source map
+	pub fn mappings(&self) -> &str {
+		&self.mappings
+	}
+
+	/// Serializes the source map to its JSON representation
+	pub fn to_json(&self) -> String {
+		format!(
+			r#"{{"version":3,"sources":[{}],"names":[],"mappings":"{}"}}"#,
+			json_string(&self.source_name),
+			self.mappings
+		)
+	}
+}
+
+/// Converts byte offsets to zero based lines and columns. The columns are counted in UTF-16
+/// code units, as expected by the JavaScript tooling consuming the source maps.
+struct LineIndex<'a> {
+	text: &'a str,
+	line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+	fn new(text: &'a str) -> Self {
+		let mut line_starts = vec![0];
+		let bytes = text.as_bytes();
+
+		for (index, byte) in bytes.iter().enumerate() {
+			let is_line_end = match byte {
+				b'\n' => true,
+				b'\r' => bytes.get(index + 1) != Some(&b'\n'),
+				_ => false,
+			};
+
+			if is_line_end {
+				line_starts.push(index + 1);
+			}
+		}
+
+		Self { text, line_starts }
+	}
+
+	fn line_column(&self, offset: TextSize) -> (i64, i64) {
+		let offset = usize::from(offset).min(self.text.len());
+		let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+		let line_start = self.line_starts[line];
+
+		let column = self
+			.text
+			.get(line_start..offset)
+			.map_or(0, |text| text.encode_utf16().count());
+
+		(line as i64, column as i64)
+	}
+}
+
+/// Appends the base 64 VLQ encoding of `value` to `output`
+fn encode_vlq(output: &mut String, value: i64) {
+	// The sign is stored in the least significant bit
+	let mut vlq = if value < 0 {
+		((-value) << 1) | 1
+	} else {
+		value << 1
+	};
+
+	loop {
+		let mut digit = vlq & 0b11111;
+		vlq >>= 5;
+		if vlq > 0 {
+			// More digits follow
+			digit |= 0b100000;
+		}
+		output.push(BASE64_CHARS[digit as usize] as char);
+
+		if vlq == 0 {
+			break;
+		}
+	}
+}
+
+/// Returns `value` as a quoted and escaped JSON string
+fn json_string(value: &str) -> String {
+	let mut escaped = String::with_capacity(value.len() + 2);
+	escaped.push('"');
+
+	for char in value.chars() {
+		match char {
+			'"' => escaped.push_str("\\\""),
+			'\\' => escaped.push_str("\\\\"),
+			'\n' => escaped.push_str("\\n"),
+			'\r' => escaped.push_str("\\r"),
+			'\t' => escaped.push_str("\\t"),
+			char if char.is_control() => escaped.push_str(&format!("\\u{:04x}", char as u32)),
+			char => escaped.push(char),
+		}
+	}
+
+	escaped.push('"');
+	escaped
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{encode_vlq, SourceMap, SourceMapping};
+
+	fn vlq(value: i64) -> String {
+		let mut encoded = String::new();
+		encode_vlq(&mut encoded, value);
+		encoded
+	}
+
+	#[test]
+	fn it_encodes_vlq_values() {
+		assert_eq!("A", vlq(0));
+		assert_eq!("C", vlq(1));
+		assert_eq!("D", vlq(-1));
+		assert_eq!("gB", vlq(16));
+		assert_eq!("2H", vlq(123));
+	}
+
+	#[test]
+	fn it_encodes_the_mappings_by_line() {
+		let mapping = |source: u32, generated: u32| SourceMapping {
+			source: source.into(),
+			generated: generated.into(),
+		};
+
+		// `a;b` formatted to `a;\nb`
+		let source_map = SourceMap::new(
+			"test.js",
+			"a;b",
+			"a;\nb",
+			&[mapping(0, 0), mapping(1, 1), mapping(2, 3)],
+		);
+
+		assert_eq!("AAAA,CAAC;AAAC", source_map.mappings());
+		assert_eq!(
+			r#"{"version":3,"sources":["test.js"],"names":[],"mappings":"AAAA,CAAC;AAAC"}"#,
+			source_map.to_json()
+		);
+	}
+
+	#[test]
+	fn it_counts_columns_in_utf16_code_units() {
+		let source_map = SourceMap::new(
+			"test.js",
+			"'😀';b",
+			"'😀';b",
+			&[SourceMapping {
+				source: 7.into(),
+				generated: 7.into(),
+			}],
+		);
+
+		assert_eq!("KAAK", source_map.mappings());
+	}
+}
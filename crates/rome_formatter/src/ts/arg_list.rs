@@ -1,15 +1,74 @@
 use crate::{
-	concat_elements, format_elements, group_elements, FormatElement, FormatResult, Formatter,
-	ToFormatElement,
+	best_fitting, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	TrailingSeparator,
 };
-use rslint_parser::ast::ArgList;
+use rslint_parser::ast::{ArgList, JsAnyArrowFunctionBody, JsAnyCallArgument, JsAnyExpression};
 
 impl ToFormatElement for ArgList {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		let l_bracket = formatter.format_token(&self.l_paren_token()?)?;
-		let args = concat_elements(formatter.format_separated(self.args())?);
 		let r_bracket = formatter.format_token(&self.r_paren_token()?)?;
 
-		Ok(group_elements(format_elements![l_bracket, args, r_bracket]))
+		let args = formatter.format_separated(self.args(), TrailingSeparator::All)?;
+
+		let expanded = group_elements(format_elements![
+			l_bracket.clone(),
+			soft_indent(join_elements(soft_line_break_or_space(), args)),
+			r_bracket.clone()
+		]);
+
+		// `foo(a, () => {` the last argument breaks on its own, the other arguments stay
+		// on the line of the call if it fits up to the start of the last argument's body
+		if should_hug_last_argument(self) {
+			let args = formatter.format_separated(self.args(), TrailingSeparator::Disallowed)?;
+
+			let hugged = format_elements![l_bracket, join_elements(space_token(), args), r_bracket];
+
+			return Ok(best_fitting(vec![hugged, expanded]));
+		}
+
+		Ok(expanded)
+	}
+}
+
+impl ToFormatElement for JsAnyCallArgument {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyCallArgument::JsAnyExpression(expression) => {
+				expression.to_format_element(formatter)
+			}
+			JsAnyCallArgument::JsSpread(spread) => spread.to_format_element(formatter),
+		}
+	}
+}
+
+/// Returns `true` if the last argument is a function with a body, an object or an array, and no other
+/// argument is one of these.
+pub(crate) fn should_hug_last_argument(args: &ArgList) -> bool {
+	let is_huggable = |argument: &JsAnyExpression| match argument {
+		JsAnyExpression::JsFunctionExpression(_)
+		| JsAnyExpression::JsObjectExpression(_)
+		| JsAnyExpression::JsArrayExpression(_) => true,
+		JsAnyExpression::JsArrowFunctionExpression(arrow) => {
+			matches!(
+				arrow.body(),
+				Some(JsAnyArrowFunctionBody::JsFunctionBody(_))
+			)
+		}
+		_ => false,
+	};
+
+	let mut arguments = args.args().iter().collect::<Vec<_>>();
+
+	match arguments.pop() {
+		Some(Ok(JsAnyCallArgument::JsAnyExpression(last))) if is_huggable(&last) => {
+			arguments.iter().all(|argument| match argument {
+				Ok(JsAnyCallArgument::JsAnyExpression(argument)) => !is_huggable(argument),
+				Ok(JsAnyCallArgument::JsSpread(_)) => true,
+				Err(_) => false,
+			})
+		}
+		_ => false,
 	}
 }
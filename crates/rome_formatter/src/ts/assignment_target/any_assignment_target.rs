@@ -1,5 +1,6 @@
 use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsAnyAssignmentTarget;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsAnyAssignmentTarget {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -7,8 +8,12 @@ impl ToFormatElement for JsAnyAssignmentTarget {
 			JsAnyAssignmentTarget::JsAnySimpleAssignmentTarget(simple) => {
 				simple.to_format_element(formatter)
 			}
-			JsAnyAssignmentTarget::JsArrayAssignmentTarget(_) => todo!(),
-			JsAnyAssignmentTarget::JsObjectAssignmentTarget(_) => todo!(),
+			JsAnyAssignmentTarget::JsArrayAssignmentTarget(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyAssignmentTarget::JsObjectAssignmentTarget(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
 		}
 	}
 }
@@ -1,5 +1,6 @@
 use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsAnySimpleAssignmentTarget;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsAnySimpleAssignmentTarget {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -7,9 +8,15 @@ impl ToFormatElement for JsAnySimpleAssignmentTarget {
 			JsAnySimpleAssignmentTarget::JsIdentifierAssignmentTarget(identifier) => {
 				identifier.to_format_element(formatter)
 			}
-			JsAnySimpleAssignmentTarget::JsStaticMemberAssignmentTarget(_) => todo!(),
-			JsAnySimpleAssignmentTarget::JsComputedMemberAssignmentTarget(_) => todo!(),
-			JsAnySimpleAssignmentTarget::JsParenthesizedAssignmentTarget(_) => todo!(),
+			JsAnySimpleAssignmentTarget::JsStaticMemberAssignmentTarget(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnySimpleAssignmentTarget::JsComputedMemberAssignmentTarget(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnySimpleAssignmentTarget::JsParenthesizedAssignmentTarget(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
 		}
 	}
 }
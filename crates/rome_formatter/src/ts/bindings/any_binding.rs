@@ -1,15 +1,18 @@
 use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsAnyBinding;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsAnyBinding {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		match self {
-			JsAnyBinding::JsObjectBinding(_) => todo!(),
+			JsAnyBinding::JsObjectBinding(node) => Ok(formatter.format_unsupported(node.syntax())),
 			JsAnyBinding::JsArrayBinding(array_pattern) => {
 				array_pattern.to_format_element(formatter)
 			}
 			JsAnyBinding::JsIdentifierBinding(single) => single.to_format_element(formatter),
-			JsAnyBinding::JsUnknownBinding(_) => todo!(),
+			JsAnyBinding::JsUnknownBinding(unknown_binding) => {
+				Ok(formatter.format_verbatim(unknown_binding.syntax()))
+			}
 		}
 	}
 }
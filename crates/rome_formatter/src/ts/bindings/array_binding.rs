@@ -1,20 +1,29 @@
+use crate::ts::bindings::format_binding_type;
 use crate::{
-	format_elements, group_elements, join_elements, space_token, FormatElement, FormatResult,
-	Formatter, ToFormatElement,
+	format_elements, group_elements, join_elements, soft_indent, soft_line_break_or_space,
+	FormatElement, FormatResult, Formatter, ToFormatElement, TrailingSeparator,
 };
-use rslint_parser::ast::{JsAnyArrayElementBinding, JsArrayBinding};
+use rslint_parser::ast::{JsAnyArrayElementBinding, JsArrayBinding, JsArrayRestBinding};
 
 impl ToFormatElement for JsArrayBinding {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		let l_bracket = formatter.format_token(&self.l_brack_token()?)?;
-		let elements = formatter.format_separated(self.elements())?;
+		let elements = formatter.format_separated(self.elements(), TrailingSeparator::Es5)?;
 		let r_bracket = formatter.format_token(&self.r_brack_token()?)?;
 
-		Ok(format_elements![group_elements(format_elements![
-			l_bracket,
-			join_elements(space_token(), elements),
-			r_bracket
-		])])
+		Ok(format_elements![
+			group_elements(format_elements![
+				l_bracket,
+				soft_indent(join_elements(soft_line_break_or_space(), elements)),
+				r_bracket
+			]),
+			format_binding_type(
+				self.question_mark_token(),
+				self.excl_token(),
+				self.ty(),
+				formatter
+			)?
+		])
 	}
 }
 
@@ -26,7 +35,16 @@ impl ToFormatElement for JsAnyArrayElementBinding {
 			JsAnyArrayElementBinding::JsBindingWithDefault(with_default) => {
 				with_default.to_format_element(formatter)
 			}
-			JsAnyArrayElementBinding::JsArrayRestBinding(_) => todo!(),
+			JsAnyArrayElementBinding::JsArrayRestBinding(rest) => rest.to_format_element(formatter),
 		}
 	}
 }
+
+impl ToFormatElement for JsArrayRestBinding {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.dotdotdot_token()?)?,
+			formatter.format_node(self.binding()?)?
+		])
+	}
+}
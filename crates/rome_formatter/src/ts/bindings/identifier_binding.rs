@@ -1,8 +1,17 @@
-use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::ts::bindings::format_binding_type;
+use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsIdentifierBinding;
 
 impl ToFormatElement for JsIdentifierBinding {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		formatter.format_token(&self.name_token()?)
+		Ok(format_elements![
+			formatter.format_token(&self.name_token()?)?,
+			format_binding_type(
+				self.question_mark_token(),
+				self.excl_token(),
+				self.ty(),
+				formatter
+			)?
+		])
 	}
 }
@@ -2,3 +2,33 @@ mod any_binding;
 mod array_binding;
 mod binding_with_default;
 mod identifier_binding;
+
+use crate::{empty_element, format_elements, FormatElement, FormatResult, Formatter};
+use rslint_parser::ast::TsTypeAnnotation;
+use rslint_parser::SyntaxToken;
+
+/// Formats the TypeScript part of a binding that follows its name or pattern: the `?` of an
+/// optional parameter, the `!` of a definite assignment and the type annotation
+fn format_binding_type(
+	question_mark: Option<SyntaxToken>,
+	excl: Option<SyntaxToken>,
+	ty: Option<TsTypeAnnotation>,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let question_mark = match question_mark {
+		Some(question_mark) => formatter.format_token(&question_mark)?,
+		None => empty_element(),
+	};
+
+	let excl = match excl {
+		Some(excl) => formatter.format_token(&excl)?,
+		None => empty_element(),
+	};
+
+	let ty = match ty {
+		Some(ty) => formatter.format_node(ty)?,
+		None => empty_element(),
+	};
+
+	Ok(format_elements![question_mark, excl, ty])
+}
@@ -1,31 +1,76 @@
+use crate::formatter::join_elements_keeping_empty_lines;
 use crate::{
 	block_indent, empty_element, format_elements, group_elements, hard_line_break, join_elements,
-	space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	space_token, FormatElement, FormatResult, Formatter, ToFormatElement, TrailingSeparator,
 };
-use rslint_parser::ast::JsClassDeclaration;
+use rslint_parser::ast::{JsClassDeclaration, TsImplementsClause};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsClassDeclaration {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = match self.declare_token() {
+			Some(declare) => format_elements![formatter.format_token(&declare)?, space_token()],
+			None => empty_element(),
+		};
+
+		let abstract_token = match self.abstract_token() {
+			Some(abstract_token) => {
+				format_elements![formatter.format_token(&abstract_token)?, space_token()]
+			}
+			None => empty_element(),
+		};
+
+		let type_parameters = match self.type_parameters() {
+			Some(type_parameters) => formatter.format_node(type_parameters)?,
+			None => empty_element(),
+		};
+
 		let extends = if let Some(extends_clause) = self.extends_clause() {
 			format_elements![space_token(), formatter.format_node(extends_clause)?]
 		} else {
 			empty_element()
 		};
 
+		let implements = if let Some(implements_clause) = self.implements_clause() {
+			format_elements![space_token(), formatter.format_node(implements_clause)?]
+		} else {
+			empty_element()
+		};
+
 		Ok(format_elements![
+			declare,
+			abstract_token,
 			formatter.format_token(&self.class_token()?)?,
 			space_token(),
 			formatter.format_node(self.id()?)?,
+			type_parameters,
 			extends,
+			implements,
 			space_token(),
 			group_elements(format_elements![
 				formatter.format_token(&self.l_curly_token()?)?,
-				block_indent(join_elements(
+				block_indent(join_elements_keeping_empty_lines(
 					hard_line_break(),
-					formatter.format_nodes(self.members())?
+					self.members()
+						.iter()
+						.map(|member| Ok((member.syntax().clone(), formatter.format_node(member)?)))
+						.collect::<FormatResult<Vec<_>>>()?
 				)),
 				formatter.format_token(&self.r_curly_token()?)?
 			])
 		])
 	}
 }
+
+impl ToFormatElement for TsImplementsClause {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.implements_token()?)?,
+			space_token(),
+			group_elements(join_elements(
+				space_token(),
+				formatter.format_separated(self.interfaces(), TrailingSeparator::Disallowed)?
+			))
+		])
+	}
+}
@@ -1,5 +1,6 @@
-use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
-use rslint_parser::ast::JsAnyClassMember;
+use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::{JsAnyClassMember, TsTypeElement};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsAnyClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -16,8 +17,18 @@ impl ToFormatElement for JsAnyClassMember {
 			}
 			JsAnyClassMember::JsGetterClassMember(getter) => getter.to_format_element(formatter),
 			JsAnyClassMember::JsSetterClassMember(setter) => setter.to_format_element(formatter),
-			JsAnyClassMember::JsUnknownMember(_) => todo!(),
-			JsAnyClassMember::TsIndexSignature(_) => todo!(),
+			JsAnyClassMember::JsUnknownMember(unknown_member) => {
+				Ok(formatter.format_verbatim(unknown_member.syntax()))
+			}
+			JsAnyClassMember::TsIndexSignature(signature) => {
+				let separator =
+					TsTypeElement::TsIndexSignature(signature.clone()).separator_token();
+
+				Ok(format_elements![
+					formatter.format_node(signature.clone())?,
+					formatter.format_or_insert_token(separator, ";")
+				])
+			}
 		}
 	}
 }
@@ -1,18 +1,25 @@
+use crate::ts::class::format_modifiers;
 use crate::{
-	format_elements, group_elements, join_elements, soft_line_break_or_space, space_token,
-	FormatElement, FormatResult, Formatter, ToFormatElement,
+	format_elements, group_elements, join_elements, soft_indent, soft_line_break_or_space,
+	space_token, token, FormatElement, FormatResult, Formatter, ToFormatElement, TrailingSeparator,
 };
 use rslint_parser::ast::{
 	JsAnyConstructorParameter, JsConstructorClassMember, JsConstructorParameterList,
 };
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsConstructorClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let body = match self.body() {
+			Ok(body) => format_elements![space_token(), formatter.format_node(body)?],
+			Err(_) => token(";"),
+		};
+
 		Ok(format_elements![
+			format_modifiers(self.syntax(), formatter)?,
 			formatter.format_node(self.name()?)?,
 			formatter.format_node(self.parameter_list()?)?,
-			space_token(),
-			formatter.format_node(self.body()?)?
+			body
 		])
 	}
 }
@@ -20,21 +27,23 @@ impl ToFormatElement for JsConstructorClassMember {
 impl ToFormatElement for JsConstructorParameterList {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		let l_bracket = formatter.format_token(&self.l_paren_token()?)?;
-		let params = formatter.format_separated(self.parameters())?;
+		let params = formatter.format_separated(self.parameters(), TrailingSeparator::All)?;
 		let r_bracket = formatter.format_token(&self.r_paren_token()?)?;
 
-		Ok(format_elements![group_elements(format_elements![
+		Ok(group_elements(format_elements![
 			l_bracket,
-			join_elements(soft_line_break_or_space(), params),
+			soft_indent(join_elements(soft_line_break_or_space(), params)),
 			r_bracket
-		])])
+		]))
 	}
 }
 
 impl ToFormatElement for JsAnyConstructorParameter {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		match self {
-			JsAnyConstructorParameter::TsConstructorParam(_) => todo!(),
+			JsAnyConstructorParameter::TsConstructorParam(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
 			JsAnyConstructorParameter::JsBindingWithDefault(binding) => {
 				binding.to_format_element(formatter)
 			}
@@ -1,14 +1,21 @@
 use crate::{
-	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
 };
 use rslint_parser::ast::JsExtendsClause;
 
 impl ToFormatElement for JsExtendsClause {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_args = match self.type_args() {
+			Some(type_args) => formatter.format_node(type_args)?,
+			None => empty_element(),
+		};
+
 		Ok(format_elements![
 			formatter.format_token(&self.extends_token()?)?,
 			space_token(),
-			formatter.format_node(self.super_class()?)?
+			formatter.format_node(self.super_class()?)?,
+			type_args
 		])
 	}
 }
@@ -1,18 +1,32 @@
+use crate::ts::class::format_modifiers;
 use crate::{
-	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	empty_element, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
 };
 use rslint_parser::ast::JsGetterClassMember;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsGetterClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let return_type = match self.return_type() {
+			Some(return_type) => formatter.format_node(return_type)?,
+			None => empty_element(),
+		};
+
+		let body = match self.body() {
+			Ok(body) => format_elements![space_token(), formatter.format_node(body)?],
+			Err(_) => token(";"),
+		};
+
 		Ok(format_elements![
+			format_modifiers(self.syntax(), formatter)?,
 			formatter.format_token(&self.get_token()?)?,
 			space_token(),
 			formatter.format_node(self.name()?)?,
 			formatter.format_token(&self.l_paren_token()?)?,
 			formatter.format_token(&self.r_paren_token()?)?,
-			space_token(),
-			formatter.format_node(self.body()?)?
+			return_type,
+			body
 		])
 	}
 }
@@ -1,24 +1,41 @@
+use crate::ts::class::format_modifiers;
 use crate::{
-	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	empty_element, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
 	ToFormatElement,
 };
 use rslint_parser::ast::JsMethodClassMember;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsMethodClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let private_token = if let Some(static_token) = self.static_token() {
-			format_elements![formatter.format_token(&static_token)?, space_token()]
-		} else {
-			empty_element()
+		let star = match self.star_token() {
+			Some(star) => formatter.format_token(&star)?,
+			None => empty_element(),
 		};
-		let name = formatter.format_node(self.name()?)?;
-		let params = formatter.format_node(self.parameter_list()?)?;
-		let body = formatter.format_node(self.body()?)?;
+
+		let type_parameters = match self.type_parameters() {
+			Some(type_parameters) => formatter.format_node(type_parameters)?,
+			None => empty_element(),
+		};
+
+		let return_type = match self.return_type() {
+			Some(return_type) => formatter.format_node(return_type)?,
+			None => empty_element(),
+		};
+
+		// Abstract methods and overloads have no body, their `;` is an empty class member
+		let body = match self.body() {
+			Ok(body) => format_elements![space_token(), formatter.format_node(body)?],
+			Err(_) => token(";"),
+		};
+
 		Ok(format_elements![
-			private_token,
-			name,
-			params,
-			space_token(),
+			format_modifiers(self.syntax(), formatter)?,
+			star,
+			formatter.format_node(self.name()?)?,
+			type_parameters,
+			formatter.format_node(self.parameter_list()?)?,
+			return_type,
 			body
 		])
 	}
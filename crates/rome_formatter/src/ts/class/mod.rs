@@ -8,3 +8,41 @@ mod getter_class_member;
 mod method_class_member;
 mod property_class_member;
 mod setter_class_member;
+
+use crate::{
+	concat_elements, format_elements, space_token, FormatElement, FormatResult, Formatter,
+};
+use rslint_parser::{SyntaxKind, SyntaxNode};
+
+/// Formats the modifiers at the start of a class member, for example `private static` or `async`,
+/// in the order of the source and each followed by a space
+fn format_modifiers(member: &SyntaxNode, formatter: &Formatter) -> FormatResult<FormatElement> {
+	let modifiers = member
+		.children_with_tokens()
+		.map(|element| element.into_token())
+		.take_while(|token| matches!(token, Some(token) if is_modifier(token.kind())))
+		.flatten()
+		.map(|modifier| {
+			Ok(format_elements![
+				formatter.format_token(&modifier)?,
+				space_token()
+			])
+		})
+		.collect::<FormatResult<Vec<_>>>()?;
+
+	Ok(concat_elements(modifiers))
+}
+
+fn is_modifier(kind: SyntaxKind) -> bool {
+	matches!(
+		kind,
+		SyntaxKind::DECLARE_KW
+			| SyntaxKind::PUBLIC_KW
+			| SyntaxKind::PROTECTED_KW
+			| SyntaxKind::PRIVATE_KW
+			| SyntaxKind::STATIC_KW
+			| SyntaxKind::ABSTRACT_KW
+			| SyntaxKind::READONLY_KW
+			| SyntaxKind::ASYNC_KW
+	)
+}
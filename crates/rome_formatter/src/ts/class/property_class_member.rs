@@ -1,15 +1,26 @@
+use crate::ts::class::format_modifiers;
 use crate::{
-	empty_element, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
 	ToFormatElement,
 };
 use rslint_parser::ast::JsPropertyClassMember;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsPropertyClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let static_token = if let Some(static_token) = self.static_token() {
-			format_elements![formatter.format_token(&static_token)?, space_token()]
-		} else {
-			empty_element()
+		let question_mark = match self.question_mark_token() {
+			Some(question_mark) => formatter.format_token(&question_mark)?,
+			None => empty_element(),
+		};
+
+		let excl = match self.excl_token() {
+			Some(excl) => formatter.format_token(&excl)?,
+			None => empty_element(),
+		};
+
+		let ty = match self.ty() {
+			Some(ty) => formatter.format_node(ty)?,
+			None => empty_element(),
 		};
 
 		let init = if let Some(init) = self.value() {
@@ -19,10 +30,13 @@ impl ToFormatElement for JsPropertyClassMember {
 		};
 
 		Ok(format_elements![
-			static_token,
+			format_modifiers(self.syntax(), formatter)?,
 			formatter.format_node(self.name()?)?,
+			question_mark,
+			excl,
+			ty,
 			init,
-			token(";")
+			formatter.format_or_insert_token(self.semicolon_token(), ";")
 		])
 	}
 }
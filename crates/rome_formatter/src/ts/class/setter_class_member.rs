@@ -1,19 +1,26 @@
+use crate::ts::class::format_modifiers;
 use crate::{
-	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	format_elements, space_token, token, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::JsSetterClassMember;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsSetterClassMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let body = match self.body() {
+			Ok(body) => format_elements![space_token(), formatter.format_node(body)?],
+			Err(_) => token(";"),
+		};
+
 		Ok(format_elements![
+			format_modifiers(self.syntax(), formatter)?,
 			formatter.format_token(&self.set_token()?)?,
 			space_token(),
 			formatter.format_node(self.name()?)?,
 			formatter.format_token(&self.l_paren_token()?)?,
 			formatter.format_node(self.parameter()?)?,
 			formatter.format_token(&self.r_paren_token()?)?,
-			space_token(),
-			formatter.format_node(self.body()?)?
+			body
 		])
 	}
 }
@@ -0,0 +1,60 @@
+use crate::{
+	block_indent, empty_element, format_elements, hard_line_break, join_elements, space_token,
+	FormatElement, FormatResult, Formatter, ToFormatElement, TrailingSeparator,
+};
+use rslint_parser::ast::{TsEnum, TsEnumMember};
+use rslint_parser::SyntaxKind;
+
+impl ToFormatElement for TsEnum {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = match self.declare_token() {
+			Some(declare) => format_elements![formatter.format_token(&declare)?, space_token()],
+			None => empty_element(),
+		};
+
+		let const_token = match self.const_token() {
+			Some(const_token) => {
+				format_elements![formatter.format_token(&const_token)?, space_token()]
+			}
+			None => empty_element(),
+		};
+
+		// Each member is on its own line, the enum is always broken like a block
+		let members = formatter.format_separated(self.enum_members(), TrailingSeparator::Es5)?;
+
+		Ok(format_elements![
+			declare,
+			const_token,
+			formatter.format_token(&self.enum_token()?)?,
+			space_token(),
+			formatter.format_node(self.name()?)?,
+			space_token(),
+			formatter.format_token(&self.l_curly_token()?)?,
+			block_indent(join_elements(hard_line_break(), members)),
+			formatter.format_token(&self.r_curly_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsEnumMember {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let name = self.name_token()?;
+		let name = if name.kind() == SyntaxKind::JS_STRING_LITERAL {
+			formatter.format_string_literal(&name)
+		} else {
+			formatter.format_token(&name)?
+		};
+
+		let initializer = match self.initializer() {
+			Some((eq_token, value)) => format_elements![
+				space_token(),
+				formatter.format_token(&eq_token)?,
+				space_token(),
+				formatter.format_node(value)?
+			],
+			None => empty_element(),
+		};
+
+		Ok(format_elements![name, initializer])
+	}
+}
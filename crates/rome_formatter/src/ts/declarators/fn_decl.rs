@@ -7,6 +7,11 @@ impl ToFormatElement for JsFunctionDeclaration {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		let mut tokens = vec![];
 
+		if let Some(token) = self.declare_token() {
+			tokens.push(formatter.format_token(&token)?);
+			tokens.push(space_token());
+		}
+
 		if let Some(token) = self.async_token() {
 			tokens.push(formatter.format_token(&token)?);
 			tokens.push(space_token());
@@ -20,9 +25,25 @@ impl ToFormatElement for JsFunctionDeclaration {
 
 		tokens.push(space_token());
 		tokens.push(formatter.format_node(self.id()?)?);
+
+		if let Some(type_parameters) = self.type_parameters() {
+			tokens.push(formatter.format_node(type_parameters)?);
+		}
+
 		tokens.push(formatter.format_node(self.parameter_list()?)?);
-		tokens.push(space_token());
-		tokens.push(formatter.format_node(self.body()?)?);
+
+		if let Some(return_type) = self.return_type() {
+			tokens.push(formatter.format_node(return_type)?);
+		}
+
+		// The overloads and the declarations of `.d.ts` files have no body
+		match self.body() {
+			Ok(body) => {
+				tokens.push(space_token());
+				tokens.push(formatter.format_node(body)?);
+			}
+			Err(_) => tokens.push(formatter.format_semicolon(self, self.semicolon_token())),
+		}
 
 		Ok(concat_elements(tokens))
 	}
@@ -0,0 +1,51 @@
+use crate::ts::types::format_type_members;
+use crate::{
+	block_indent, empty_element, format_elements, group_elements, hard_line_break, join_elements,
+	space_token, FormatElement, FormatResult, Formatter, ToFormatElement, TrailingSeparator,
+};
+use rslint_parser::ast::TsInterfaceDecl;
+
+impl ToFormatElement for TsInterfaceDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = match self.declare_token() {
+			Some(declare) => format_elements![formatter.format_token(&declare)?, space_token()],
+			None => empty_element(),
+		};
+
+		let type_parameters = match self.type_parameters() {
+			Some(type_parameters) => formatter.format_node(type_parameters)?,
+			None => empty_element(),
+		};
+
+		let extends = match (self.extends_token(), self.extends_list()) {
+			(Some(extends_token), Some(extends_list)) => format_elements![
+				space_token(),
+				formatter.format_token(&extends_token)?,
+				space_token(),
+				group_elements(join_elements(
+					space_token(),
+					formatter.format_separated(extends_list, TrailingSeparator::Disallowed)?
+				))
+			],
+			_ => empty_element(),
+		};
+
+		// Like class bodies, the members of an interface are always on their own line
+		let members = format_type_members(self.type_members(), formatter)?;
+
+		Ok(format_elements![
+			declare,
+			formatter.format_token(&self.interface_keyword()?)?,
+			space_token(),
+			formatter.format_node(self.name()?)?,
+			type_parameters,
+			extends,
+			space_token(),
+			group_elements(format_elements![
+				formatter.format_token(&self.l_curly_token()?)?,
+				block_indent(join_elements(hard_line_break(), members)),
+				formatter.format_token(&self.r_curly_token()?)?
+			])
+		])
+	}
+}
@@ -1,2 +1,6 @@
+mod enum_decl;
 mod fn_decl;
+mod interface_decl;
+mod module_decl;
+mod type_alias_decl;
 mod variable_declaration_statement;
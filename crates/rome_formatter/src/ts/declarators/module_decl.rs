@@ -0,0 +1,85 @@
+use crate::ts::statements::format_statements;
+use crate::{
+	block_indent, empty_element, format_elements, space_token, FormatElement, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{TsModuleBlock, TsModuleDecl, TsNamespaceBody, TsNamespaceDecl};
+
+impl ToFormatElement for TsNamespaceDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = match self.declare_token() {
+			Ok(declare) => format_elements![formatter.format_token(&declare)?, space_token()],
+			Err(_) => empty_element(),
+		};
+
+		let namespace = match self.namespace_keyword() {
+			Some(namespace) => format_elements![formatter.format_token(&namespace)?, space_token()],
+			None => empty_element(),
+		};
+
+		// The names of `namespace a.b.c {}` are nested declarations starting with the `.`
+		let dot = match self.dot_token() {
+			Some(dot) => formatter.format_token(&dot)?,
+			None => empty_element(),
+		};
+
+		let body = match self.body()? {
+			TsNamespaceBody::TsModuleBlock(block) => {
+				format_elements![space_token(), formatter.format_node(block)?]
+			}
+			TsNamespaceBody::TsNamespaceDecl(nested) => formatter.format_node(nested)?,
+		};
+
+		Ok(format_elements![
+			declare,
+			namespace,
+			dot,
+			formatter.format_node(self.name()?)?,
+			body
+		])
+	}
+}
+
+impl ToFormatElement for TsModuleDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = match self.declare_token() {
+			Ok(declare) => format_elements![formatter.format_token(&declare)?, space_token()],
+			Err(_) => empty_element(),
+		};
+
+		let name = if let Some(global) = self.global_keyword() {
+			formatter.format_token(&global)?
+		} else {
+			let module_keyword = match self.module_keyword() {
+				Some(module_keyword) => formatter.format_token(&module_keyword)?,
+				None => empty_element(),
+			};
+
+			let name = match (self.string_name_token(), self.name()) {
+				(Some(string_name), _) => formatter.format_string_literal(&string_name),
+				(None, Some(name)) => formatter.format_node(name)?,
+				(None, None) => empty_element(),
+			};
+
+			format_elements![module_keyword, space_token(), name]
+		};
+
+		// `declare module "a";` declares a module without specifying its exports
+		let body = match self.module_body() {
+			Some(body) => format_elements![space_token(), formatter.format_node(body)?],
+			None => formatter.format_semicolon(self, None),
+		};
+
+		Ok(format_elements![declare, name, body])
+	}
+}
+
+impl ToFormatElement for TsModuleBlock {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			block_indent(format_statements(self.statements(), formatter)),
+			formatter.format_token(&self.r_curly_token()?)?
+		])
+	}
+}
@@ -0,0 +1,32 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::TsTypeAliasDecl;
+
+impl ToFormatElement for TsTypeAliasDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = match self.declare_token() {
+			Some(declare) => format_elements![formatter.format_token(&declare)?, space_token()],
+			None => empty_element(),
+		};
+
+		let type_parameters = match self.type_parameters() {
+			Some(type_parameters) => formatter.format_node(type_parameters)?,
+			None => empty_element(),
+		};
+
+		Ok(format_elements![
+			declare,
+			formatter.format_token(&self.type_keyword()?)?,
+			space_token(),
+			formatter.format_node(self.name()?)?,
+			type_parameters,
+			space_token(),
+			formatter.format_token(&self.eq_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+			formatter.format_semicolon(self, self.semicolon_token())
+		])
+	}
+}
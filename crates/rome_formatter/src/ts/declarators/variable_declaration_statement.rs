@@ -1,6 +1,6 @@
 use crate::{
-	empty_element, format_elements, join_elements, space_token, token, FormatElement, FormatResult,
-	Formatter, ToFormatElement,
+	empty_element, format_elements, join_elements, space_token, FormatElement, FormatResult,
+	Formatter, ToFormatElement, TrailingSeparator,
 };
 use rslint_parser::ast::{
 	JsVariableDeclaration, JsVariableDeclarationStatement, JsVariableDeclarator,
@@ -8,16 +8,23 @@ use rslint_parser::ast::{
 
 impl ToFormatElement for JsVariableDeclarationStatement {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let declare = match self.declare_token() {
+			Some(declare) => format_elements![formatter.format_token(&declare)?, space_token()],
+			None => empty_element(),
+		};
+
 		Ok(format_elements![
+			declare,
 			formatter.format_node(self.declaration()?)?,
-			token(";"),
+			formatter.format_semicolon(self, self.semicolon_token()),
 		])
 	}
 }
 
 impl ToFormatElement for JsVariableDeclaration {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let declarators = formatter.format_separated(self.declarators())?;
+		let declarators =
+			formatter.format_separated(self.declarators(), TrailingSeparator::Disallowed)?;
 
 		Ok(format_elements![
 			formatter.format_token(&self.kind_token()?)?,
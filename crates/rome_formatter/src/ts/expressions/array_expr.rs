@@ -1,22 +1,57 @@
 use crate::{
-	empty_element, format_elements, group_elements, if_group_breaks, join_elements, soft_indent,
-	soft_line_break_or_space, token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	empty_element, fill_elements, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, FormatElement, FormatResult, Formatter, ToFormatElement,
+	TrailingSeparator,
 };
-use rslint_parser::ast::{JsArrayExpression, JsArrayHole};
+use rslint_parser::ast::{
+	JsAnyArrayElement, JsAnyExpression, JsAnyLiteralExpression, JsArrayExpression, JsArrayHole,
+};
+use rslint_parser::T;
 
 impl ToFormatElement for JsArrayExpression {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let elements = formatter.format_separated(self.elements())?;
+		let elements = formatter.format_separated(self.elements(), TrailingSeparator::Es5)?;
+
+		// An array of numbers, e.g. a matrix, fills each line with as many numbers as fit
+		let elements = if is_number_list(self) {
+			fill_elements(soft_line_break_or_space(), elements)
+		} else {
+			join_elements(soft_line_break_or_space(), elements)
+		};
 
 		Ok(group_elements(format_elements!(
 			formatter.format_token(&self.l_brack_token()?)?,
-			soft_indent(join_elements(soft_line_break_or_space(), elements)),
-			if_group_breaks(token(",")),
+			soft_indent(elements),
 			formatter.format_token(&self.r_brack_token()?)?,
 		)))
 	}
 }
 
+/// Returns `true` if the array has more than one element and all its elements are numbers,
+/// optionally signed: `[1, -2.5, +3]`
+fn is_number_list(array: &JsArrayExpression) -> bool {
+	let is_number = |expression: &JsAnyExpression| {
+		matches!(
+			expression,
+			JsAnyExpression::JsAnyLiteralExpression(
+				JsAnyLiteralExpression::JsNumberLiteralExpression(_)
+			)
+		)
+	};
+
+	let elements = array.elements();
+
+	elements.len() > 1
+		&& elements.iter().all(|element| match element {
+			Ok(JsAnyArrayElement::JsAnyExpression(JsAnyExpression::JsUnaryExpression(unary))) => {
+				matches!(unary.operator(), Ok(operator) if matches!(operator.kind(), T![+] | T![-]))
+					&& matches!(unary.argument(), Ok(argument) if is_number(&argument))
+			}
+			Ok(JsAnyArrayElement::JsAnyExpression(expression)) => is_number(&expression),
+			_ => false,
+		})
+}
+
 impl ToFormatElement for JsArrayHole {
 	fn to_format_element(&self, _: &Formatter) -> FormatResult<FormatElement> {
 		Ok(empty_element())
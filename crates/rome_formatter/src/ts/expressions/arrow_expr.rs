@@ -18,6 +18,10 @@ impl ToFormatElement for JsArrowFunctionExpression {
 			));
 		}
 
+		if let Some(type_parameters) = self.type_parameters() {
+			tokens.push(formatter.format_node(type_parameters)?);
+		}
+
 		if let Some(params) = self.parameter_list() {
 			match params {
 				JsAnyArrowFunctionParameters::JsIdentifierBinding(name) => {
@@ -31,6 +35,10 @@ impl ToFormatElement for JsArrowFunctionExpression {
 			}
 		}
 
+		if let Some(return_type) = self.return_type() {
+			tokens.push(formatter.format_node(return_type)?);
+		}
+
 		tokens.push(space_token());
 		tokens.push(formatter.format_token(&self.fat_arrow_token()?)?);
 		tokens.push(space_token());
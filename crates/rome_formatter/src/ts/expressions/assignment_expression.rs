@@ -0,0 +1,16 @@
+use crate::ts::expressions::format_assignment_right;
+use crate::{
+	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::JsAssignmentExpression;
+
+impl ToFormatElement for JsAssignmentExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.left()?)?,
+			space_token(),
+			formatter.format_token(&self.operator_token()?)?,
+			format_assignment_right(self.right()?, formatter)?,
+		])
+	}
+}
@@ -0,0 +1,219 @@
+use crate::ts::expressions::parent_without_parentheses;
+use crate::{
+	concat_elements, format_elements, group_elements, indent, soft_line_break_or_space,
+	space_token, FormatElement, FormatError, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{JsAnyExpression, JsBinaryExpression, JsLogicalExpression};
+use rslint_parser::{AstNode, SyntaxKind, SyntaxNode, SyntaxToken, T};
+
+impl ToFormatElement for JsBinaryExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		format_binary_like_expression(BinaryLikeExpression::Binary(self.clone()), formatter)
+	}
+}
+
+impl ToFormatElement for JsLogicalExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		format_binary_like_expression(BinaryLikeExpression::Logical(self.clone()), formatter)
+	}
+}
+
+/// Binary and logical expressions are formatted the same way
+#[derive(Debug, Clone)]
+pub(crate) enum BinaryLikeExpression {
+	Binary(JsBinaryExpression),
+	Logical(JsLogicalExpression),
+}
+
+impl BinaryLikeExpression {
+	pub(crate) fn cast(expression: JsAnyExpression) -> Option<Self> {
+		match expression {
+			JsAnyExpression::JsBinaryExpression(binary) => {
+				Some(BinaryLikeExpression::Binary(binary))
+			}
+			JsAnyExpression::JsLogicalExpression(logical) => {
+				Some(BinaryLikeExpression::Logical(logical))
+			}
+			_ => None,
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			BinaryLikeExpression::Binary(binary) => binary.syntax(),
+			BinaryLikeExpression::Logical(logical) => logical.syntax(),
+		}
+	}
+
+	fn left(&self) -> FormatResult<JsAnyExpression> {
+		let left = match self {
+			BinaryLikeExpression::Binary(binary) => binary.left()?,
+			BinaryLikeExpression::Logical(logical) => logical.left()?,
+		};
+
+		Ok(left)
+	}
+
+	fn operator(&self) -> FormatResult<SyntaxToken> {
+		let operator = match self {
+			BinaryLikeExpression::Binary(binary) => binary.operator()?,
+			BinaryLikeExpression::Logical(logical) => logical.operator()?,
+		};
+
+		Ok(operator)
+	}
+
+	fn right(&self) -> FormatResult<JsAnyExpression> {
+		match self {
+			BinaryLikeExpression::Binary(binary) => binary.right(),
+			BinaryLikeExpression::Logical(logical) => logical.right(),
+		}
+		.ok_or(FormatError::MissingRequiredChild)
+	}
+
+	/// Whether the right side of the logical expression is an object or an array literal that
+	/// is kept on the line of the operator, for example `options || {`
+	pub(crate) fn should_inline(&self) -> bool {
+		match self {
+			BinaryLikeExpression::Binary(_) => false,
+			BinaryLikeExpression::Logical(logical) => match logical.right() {
+				Some(JsAnyExpression::JsObjectExpression(object)) => !object.members().is_empty(),
+				Some(JsAnyExpression::JsArrayExpression(array)) => !array.elements().is_empty(),
+				_ => false,
+			},
+		}
+	}
+}
+
+/// Formats a chain of binary or logical expressions.
+///
+/// The operations with the same precedence are flattened in a single group, for example
+/// `a + b + c`, that puts each operand on its own line if the chain doesn't fit on one.
+fn format_binary_like_expression(
+	expression: BinaryLikeExpression,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let should_inline = expression.should_inline();
+	let layout = BinaryLikeLayout::from_parent(parent_without_parentheses(expression.syntax()));
+
+	// The chains are left associative: `a + b + c` is `(a + b) + c`
+	let mut operations = Vec::new();
+	let mut current = expression;
+	let first = loop {
+		let operator = current.operator()?;
+		let left = current.left()?;
+		operations.push((operator.clone(), current.right()?, current.should_inline()));
+
+		match BinaryLikeExpression::cast(left.clone()) {
+			Some(left) if should_flatten(operator.kind(), left.operator()?.kind()) => {
+				current = left;
+			}
+			_ => break left,
+		}
+	};
+
+	let mut rest = Vec::with_capacity(operations.len());
+	for (operator, right, inline_right) in operations.into_iter().rev() {
+		let line = if inline_right {
+			space_token()
+		} else {
+			soft_line_break_or_space()
+		};
+
+		rest.push(format_elements![
+			space_token(),
+			formatter.format_token(&operator)?,
+			line,
+			formatter.format_node(right)?
+		]);
+	}
+
+	let first = formatter.format_node(first)?;
+	let rest = concat_elements(rest);
+
+	Ok(match layout {
+		BinaryLikeLayout::InParentheses => format_elements![first, rest],
+		BinaryLikeLayout::NotIndented => group_elements(format_elements![first, rest]),
+		BinaryLikeLayout::Indented if should_inline => {
+			group_elements(format_elements![first, rest])
+		}
+		BinaryLikeLayout::Indented => group_elements(format_elements![first, indent(rest)]),
+	})
+}
+
+/// How the operands of a broken chain are laid out, it depends on where the chain is
+enum BinaryLikeLayout {
+	/// The chain is the test of a statement, `if (a && b)`, it breaks with the parentheses
+	/// of the statement
+	InParentheses,
+	/// The node containing the chain already indents it, for example the right side of an assignment
+	NotIndented,
+	/// The operands after the first are indented
+	Indented,
+}
+
+impl BinaryLikeLayout {
+	fn from_parent(parent: Option<SyntaxNode>) -> Self {
+		match parent.map(|parent| parent.kind()) {
+			Some(
+				SyntaxKind::JS_IF_STATEMENT
+				| SyntaxKind::JS_WHILE_STATEMENT
+				| SyntaxKind::JS_DO_WHILE_STATEMENT
+				| SyntaxKind::JS_SWITCH_STATEMENT,
+			) => BinaryLikeLayout::InParentheses,
+			Some(
+				SyntaxKind::JS_RETURN_STATEMENT
+				| SyntaxKind::JS_THROW_STATEMENT
+				| SyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+				| SyntaxKind::JS_CONDITIONAL_EXPRESSION
+				| SyntaxKind::JS_EQUAL_VALUE_CLAUSE
+				| SyntaxKind::JS_ASSIGNMENT_EXPRESSION
+				| SyntaxKind::JS_PROPERTY_OBJECT_MEMBER,
+			) => BinaryLikeLayout::NotIndented,
+			_ => BinaryLikeLayout::Indented,
+		}
+	}
+}
+
+/// Returns the precedence of a binary or logical operator, the higher the tighter it binds
+pub(super) fn precedence(operator: SyntaxKind) -> u8 {
+	match operator {
+		T![??] => 1,
+		T![||] => 2,
+		T![&&] => 3,
+		T![|] => 4,
+		T![^] => 5,
+		T![&] => 6,
+		T![==] | T![!=] | T![===] | T![!==] => 7,
+		T![<] | T![>] | T![<=] | T![>=] | T![in] | T![instanceof] => 8,
+		T![<<] | T![>>] | T![>>>] => 9,
+		T![+] | T![-] => 10,
+		T![*] | T![/] | T![%] => 11,
+		T![**] => 12,
+		_ => 0,
+	}
+}
+
+/// Whether the operation of the left operand can be part of the same chain as its parent
+/// operation, for example `a + b - c`. The operations that are easier to read when grouped
+/// aren't flattened: `a * b % c`, `a == b == c`, `a << b << c`.
+pub(super) fn should_flatten(parent_operator: SyntaxKind, operator: SyntaxKind) -> bool {
+	if precedence(parent_operator) != precedence(operator) {
+		return false;
+	}
+
+	let is_equality = |operator| matches!(operator, T![==] | T![!=] | T![===] | T![!==]);
+	let is_multiplicative = |operator| matches!(operator, T![*] | T![/] | T![%]);
+	let is_bit_shift = |operator| matches!(operator, T![<<] | T![>>] | T![>>>]);
+
+	match (parent_operator, operator) {
+		// `**` is right associative
+		(T![**], _) => false,
+		(parent, operator) if is_equality(parent) && is_equality(operator) => false,
+		(T![%], operator) if is_multiplicative(operator) => false,
+		(parent, T![%]) if is_multiplicative(parent) => false,
+		(parent, operator) if parent != operator && is_multiplicative(operator) => false,
+		(parent, operator) if is_bit_shift(parent) && is_bit_shift(operator) => false,
+		_ => true,
+	}
+}
@@ -1,10 +1,40 @@
-use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
-use rslint_parser::ast::CallExpr;
+use crate::ts::expressions::member_chain::format_member_chain;
+use crate::{
+	empty_element, format_elements, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{CallExpr, JsAnyExpression};
 
 impl ToFormatElement for CallExpr {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let name = formatter.format_node(self.callee()?)?;
-		let arguments = formatter.format_node(self.arguments()?)?;
-		Ok(format_elements![name, arguments])
+		match self.callee()? {
+			JsAnyExpression::JsStaticMemberExpression(_)
+			| JsAnyExpression::JsComputedMemberExpression(_) => format_member_chain(self, formatter),
+			callee => Ok(format_elements![
+				formatter.format_node(callee)?,
+				format_call_arguments(self, formatter)?
+			]),
+		}
 	}
 }
+
+/// Formats the part of the call expression after its callee: `?.<T>(arguments)`
+pub(super) fn format_call_arguments(
+	call: &CallExpr,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let optional_chain = match call.optional_chain_token() {
+		Some(token) => formatter.format_token(&token)?,
+		None => empty_element(),
+	};
+
+	let type_args = match call.type_args() {
+		Some(type_args) => formatter.format_node(type_args)?,
+		None => empty_element(),
+	};
+
+	Ok(format_elements![
+		optional_chain,
+		type_args,
+		formatter.format_node(call.arguments()?)?
+	])
+}
@@ -0,0 +1,34 @@
+use crate::{
+	empty_element, format_elements, group_elements, soft_indent, FormatElement, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::JsComputedMemberExpression;
+
+impl ToFormatElement for JsComputedMemberExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.object()?)?,
+			format_computed_member(self, formatter)?,
+		])
+	}
+}
+
+/// Formats the part of the computed member expression after its object: `?.[member]`
+pub(super) fn format_computed_member(
+	expression: &JsComputedMemberExpression,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let optional_chain = match expression.optional_chain_token_token() {
+		Some(token) => formatter.format_token(&token)?,
+		None => empty_element(),
+	};
+
+	Ok(format_elements![
+		optional_chain,
+		group_elements(format_elements![
+			formatter.format_token(&expression.l_brack_token()?)?,
+			soft_indent(formatter.format_node(expression.member()?)?),
+			formatter.format_token(&expression.r_brack_token()?)?,
+		])
+	])
+}
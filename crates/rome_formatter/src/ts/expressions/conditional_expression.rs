@@ -0,0 +1,44 @@
+use crate::{
+	concat_elements, format_elements, group_elements, indent, soft_line_break_or_space,
+	space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{JsAnyExpression, JsConditionalExpression};
+
+impl ToFormatElement for JsConditionalExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let mut branches = Vec::new();
+		let mut current = self.clone();
+
+		// Conditionals in the alternate branch are flattened to make the chain
+		// `a ? b : c ? d : e` read like an `else if`
+		loop {
+			branches.push(format_elements![
+				soft_line_break_or_space(),
+				formatter.format_token(&current.question_mark_token()?)?,
+				space_token(),
+				formatter.format_node(current.consequent()?)?,
+				soft_line_break_or_space(),
+				formatter.format_token(&current.colon_token()?)?,
+				space_token(),
+			]);
+
+			match current.alternate()? {
+				JsAnyExpression::JsConditionalExpression(alternate) => {
+					branches.push(formatter.format_node(alternate.test()?)?);
+					current = alternate;
+				}
+				alternate => {
+					branches.push(formatter.format_node(alternate)?);
+					break;
+				}
+			}
+		}
+
+		let branches = concat_elements(branches);
+
+		Ok(group_elements(format_elements![
+			formatter.format_node(self.test()?)?,
+			indent(branches)
+		]))
+	}
+}
@@ -1,51 +1,79 @@
-use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::ts::expressions::needs_parentheses;
+use crate::{format_elements, token, FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsAnyExpression;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsAnyExpression {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		match self {
+		let expression = match self {
 			JsAnyExpression::JsArrowFunctionExpression(arrow) => arrow.to_format_element(formatter),
 			JsAnyExpression::JsAnyLiteralExpression(literal) => {
 				literal.to_format_element(formatter)
 			}
-			JsAnyExpression::Template(_) => todo!(),
+			JsAnyExpression::Template(template) => template.to_format_element(formatter),
 			JsAnyExpression::JsReferenceIdentifierExpression(name_ref) => {
 				name_ref.to_format_element(formatter)
 			}
-			JsAnyExpression::JsThisExpression(_) => todo!(),
+			JsAnyExpression::JsThisExpression(expr) => expr.to_format_element(formatter),
 			JsAnyExpression::JsArrayExpression(array_expression) => {
 				array_expression.to_format_element(formatter)
 			}
 			JsAnyExpression::JsObjectExpression(object_expression) => {
 				object_expression.to_format_element(formatter)
 			}
-			JsAnyExpression::JsParenthesizedExpression(_) => todo!(),
-			JsAnyExpression::JsComputedMemberExpression(_) => todo!(),
-			JsAnyExpression::JsStaticMemberExpression(_) => todo!(),
-			JsAnyExpression::NewExpr(_) => todo!(),
+			JsAnyExpression::JsParenthesizedExpression(expr) => expr.to_format_element(formatter),
+			JsAnyExpression::JsComputedMemberExpression(expr) => expr.to_format_element(formatter),
+			JsAnyExpression::JsStaticMemberExpression(expr) => expr.to_format_element(formatter),
+			JsAnyExpression::NewExpr(expr) => expr.to_format_element(formatter),
 			JsAnyExpression::CallExpr(call_expression) => {
 				call_expression.to_format_element(formatter)
 			}
-			JsAnyExpression::JsUnaryExpression(_) => todo!(),
-			JsAnyExpression::JsBinaryExpression(_) => todo!(),
-			JsAnyExpression::JsConditionalExpression(_) => todo!(),
-			JsAnyExpression::JsAssignmentExpression(_) => todo!(),
+			JsAnyExpression::JsUnaryExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::JsBinaryExpression(expr) => expr.to_format_element(formatter),
+			JsAnyExpression::JsConditionalExpression(expr) => expr.to_format_element(formatter),
+			JsAnyExpression::JsAssignmentExpression(expr) => expr.to_format_element(formatter),
 			JsAnyExpression::JsSequenceExpression(expr) => expr.to_format_element(formatter),
-			JsAnyExpression::JsFunctionExpression(_) => todo!(),
-			JsAnyExpression::JsClassExpression(_) => todo!(),
-			JsAnyExpression::NewTarget(_) => todo!(),
-			JsAnyExpression::ImportMeta(_) => todo!(),
-			JsAnyExpression::JsImportCallExpression(_) => todo!(),
-			JsAnyExpression::JsYieldExpression(_) => todo!(),
-			JsAnyExpression::JsAwaitExpression(_) => todo!(),
-			JsAnyExpression::TsNonNull(_) => todo!(),
-			JsAnyExpression::TsAssertion(_) => todo!(),
-			JsAnyExpression::TsConstAssertion(_) => todo!(),
-			JsAnyExpression::JsPreUpdateExpression(_) => todo!(),
-			JsAnyExpression::JsPostUpdateExpression(_) => todo!(),
-			JsAnyExpression::JsUnknownExpression(_) => todo!(),
-			JsAnyExpression::JsLogicalExpression(_) => todo!(),
+			JsAnyExpression::JsFunctionExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::JsClassExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::NewTarget(node) => Ok(formatter.format_unsupported(node.syntax())),
+			JsAnyExpression::ImportMeta(node) => Ok(formatter.format_unsupported(node.syntax())),
+			JsAnyExpression::JsImportCallExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::JsYieldExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::JsAwaitExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::TsNonNull(node) => Ok(formatter.format_unsupported(node.syntax())),
+			JsAnyExpression::TsAssertion(node) => Ok(formatter.format_unsupported(node.syntax())),
+			JsAnyExpression::TsConstAssertion(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::JsPreUpdateExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::JsPostUpdateExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
+			JsAnyExpression::JsUnknownExpression(unknown_expression) => {
+				Ok(formatter.format_verbatim(unknown_expression.syntax()))
+			}
+			JsAnyExpression::JsLogicalExpression(expr) => expr.to_format_element(formatter),
 			JsAnyExpression::JsSuperExpression(expr) => expr.to_format_element(formatter),
+		}?;
+
+		if needs_parentheses(self) {
+			Ok(format_elements![token("("), expression, token(")")])
+		} else {
+			Ok(expression)
 		}
 	}
 }
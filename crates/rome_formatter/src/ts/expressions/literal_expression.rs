@@ -1,22 +1,13 @@
-use crate::{token, FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::{
 	JsAnyLiteralExpression, JsBigIntLiteralExpression, JsBooleanLiteralExpression,
 	JsNullLiteralExpression, JsNumberLiteralExpression, JsStringLiteralExpression,
 };
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsStringLiteralExpression {
-	fn to_format_element(&self, _: &Formatter) -> FormatResult<FormatElement> {
-		let value_token = self.value_token()?;
-		let quoted = value_token.text_trimmed();
-
-		// uses single quotes
-		if quoted.starts_with('\'') {
-			let s = &quoted[1..quoted.len() - 1];
-			let s = format!("\"{}\"", s);
-			Ok(token(s))
-		} else {
-			Ok(token(quoted))
-		}
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(formatter.format_string_literal(&self.value_token()?))
 	}
 }
 
@@ -62,7 +53,9 @@ impl ToFormatElement for JsAnyLiteralExpression {
 			JsAnyLiteralExpression::JsNullLiteralExpression(null_literal) => {
 				null_literal.to_format_element(formatter)
 			}
-			JsAnyLiteralExpression::JsRegexLiteralExpression(_) => todo!(),
+			JsAnyLiteralExpression::JsRegexLiteralExpression(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
 		}
 	}
 }
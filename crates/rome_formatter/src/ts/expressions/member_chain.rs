@@ -0,0 +1,242 @@
+//! Formats chains of member accesses and calls, for example `promise.then(a).catch(b).finally(c)`.
+//!
+//! The chain is split in groups: the first group is the head of the chain, the following groups
+//! start with a member access and contain the calls and computed accesses that follow it.
+//!
+//! ```js
+//! promise // head
+//!   .then(a) // group
+//!   .catch(b) // group
+//! ```
+//!
+//! The chain stays on one line if it fits, otherwise each group after the head goes on its own line.
+
+use crate::ts::expressions::call_expression::format_call_arguments;
+use crate::ts::expressions::computed_member_expression::format_computed_member;
+use crate::{
+	concat_elements, format_elements, group_elements, hard_line_break, indent, soft_line_break,
+	FormatElement, FormatResult, Formatter, IndentStyle,
+};
+use rslint_parser::ast::{
+	CallExpr, JsAnyArrowFunctionBody, JsAnyCallArgument, JsAnyExpression,
+	JsComputedMemberExpression, JsStaticMemberExpression,
+};
+use rslint_parser::{AstNode, SyntaxNode};
+
+/// A member access or a call of the chain
+enum ChainLink {
+	Call(CallExpr),
+	StaticMember(JsStaticMemberExpression),
+	ComputedMember(JsComputedMemberExpression),
+}
+
+impl ChainLink {
+	fn is_call(&self) -> bool {
+		matches!(self, ChainLink::Call(_))
+	}
+
+	/// Computed accesses with a literal, `a[0]`, are kept with what precedes them
+	fn is_computed_literal_access(&self) -> bool {
+		match self {
+			ChainLink::ComputedMember(member) => matches!(
+				member.member(),
+				Ok(JsAnyExpression::JsAnyLiteralExpression(_))
+			),
+			_ => false,
+		}
+	}
+
+	fn is_member(&self) -> bool {
+		!self.is_call()
+	}
+
+	/// Whether the call has a function in its arguments: a chain with more than two calls like that
+	/// is always broken, for example `promise.then(() => {}).catch(() => {}).finally(() => {})`
+	fn has_function_argument(&self) -> bool {
+		let call = match self {
+			ChainLink::Call(call) => call,
+			_ => return false,
+		};
+
+		let arguments = match call.arguments() {
+			Ok(arguments) => arguments,
+			Err(_) => return false,
+		};
+
+		arguments.args().iter().any(|argument| match argument {
+			Ok(JsAnyCallArgument::JsAnyExpression(JsAnyExpression::JsFunctionExpression(_))) => {
+				true
+			}
+			Ok(JsAnyCallArgument::JsAnyExpression(JsAnyExpression::JsArrowFunctionExpression(
+				arrow,
+			))) => {
+				matches!(
+					arrow.body(),
+					Some(JsAnyArrowFunctionBody::JsFunctionBody(_))
+				)
+			}
+			_ => false,
+		})
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			ChainLink::Call(call) => call.syntax(),
+			ChainLink::StaticMember(member) => member.syntax(),
+			ChainLink::ComputedMember(member) => member.syntax(),
+		}
+	}
+
+	/// Formats the part of the link that follows its object or callee.
+	///
+	/// The link isn't formatted with [Formatter::format_node] because its node contains the whole
+	/// chain before it, so the end of the node is mapped here. A suppression comment can't apply to
+	/// the link alone: its first token is the first token of the chain, that is already suppressed.
+	fn format(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let content = match self {
+			ChainLink::Call(call) => format_call_arguments(call, formatter)?,
+			ChainLink::StaticMember(member) => format_elements![
+				formatter.format_token(&member.operator()?)?,
+				formatter.format_node(member.member()?)?
+			],
+			ChainLink::ComputedMember(member) => format_computed_member(member, formatter)?,
+		};
+
+		Ok(format_elements![
+			content,
+			formatter.format_node_end(self.syntax())
+		])
+	}
+}
+
+/// Formats a call whose callee is a member expression, `a.b()`, as a member chain
+pub(super) fn format_member_chain(
+	call: &CallExpr,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let mut links = Vec::new();
+	let mut current = JsAnyExpression::CallExpr(call.clone());
+
+	let head = loop {
+		current = match current {
+			JsAnyExpression::CallExpr(call) => {
+				let callee = call.callee()?;
+				links.push(ChainLink::Call(call));
+				callee
+			}
+			JsAnyExpression::JsStaticMemberExpression(member) => {
+				let object = member.object()?;
+				links.push(ChainLink::StaticMember(member));
+				object
+			}
+			JsAnyExpression::JsComputedMemberExpression(member) => {
+				let object = member.object()?;
+				links.push(ChainLink::ComputedMember(member));
+				object
+			}
+			head => break head,
+		};
+	};
+
+	links.reverse();
+
+	let mut links = links.into_iter().peekable();
+
+	// The first group is the head followed by its calls and computed accesses, `a()[0]`, and by the
+	// members that are followed by another member, `this.items` in `this.items.map()`
+	let mut first_group = vec![formatter.format_node(head.clone())?];
+
+	while let Some(link) = links.next_if(|link| link.is_call() || link.is_computed_literal_access())
+	{
+		first_group.push(link.format(formatter)?);
+	}
+
+	let links: Vec<_> = links.collect();
+	let mut index = 0;
+
+	while index + 1 < links.len() && links[index].is_member() && links[index + 1].is_member() {
+		first_group.push(links[index].format(formatter)?);
+		index += 1;
+	}
+
+	let call_count = links.iter().filter(|link| link.is_call()).count();
+	let has_function_argument = links.iter().any(ChainLink::has_function_argument);
+
+	// Each of the other groups starts with a member access that follows a call
+	let mut groups: Vec<Vec<FormatElement>> = Vec::new();
+	let mut has_seen_call = false;
+
+	for link in &links[index..] {
+		if has_seen_call && link.is_member() && !link.is_computed_literal_access() {
+			has_seen_call = false;
+			groups.push(Vec::new());
+		}
+
+		if groups.is_empty() {
+			groups.push(Vec::new());
+		}
+
+		has_seen_call |= link.is_call();
+		groups.last_mut().unwrap().push(link.format(formatter)?);
+	}
+
+	let should_merge =
+		first_group.len() == 1 && !groups.is_empty() && is_short_head(&head, formatter);
+	let group_count = groups.len() + 1;
+
+	let mut groups = groups.into_iter().map(concat_elements);
+
+	if should_merge {
+		first_group.extend(groups.next());
+	}
+
+	let cutoff = if should_merge { 3 } else { 2 };
+
+	if group_count <= cutoff {
+		first_group.extend(groups);
+		return Ok(concat_elements(first_group));
+	}
+
+	let separator = if call_count > 2 && has_function_argument {
+		hard_line_break()
+	} else {
+		soft_line_break()
+	};
+
+	let rest = concat_elements(groups.map(|group| format_elements![separator.clone(), group]));
+
+	Ok(group_elements(format_elements![
+		concat_elements(first_group),
+		indent(rest)
+	]))
+}
+
+/// Whether the first member of the chain is kept on the line of the head, `this.x`.
+///
+/// Short identifiers, that are at most as wide as the indentation, and factories, `Foo.create()`,
+/// are kept with their first member too: breaking the chain after them doesn't make the line shorter.
+fn is_short_head(head: &JsAnyExpression, formatter: &Formatter) -> bool {
+	match head {
+		JsAnyExpression::JsThisExpression(_) => true,
+		JsAnyExpression::JsReferenceIdentifierExpression(identifier) => {
+			let name = match identifier.name_token() {
+				Ok(name) => name,
+				Err(_) => return false,
+			};
+
+			let name = name.text_trimmed();
+
+			// A tab is printed as wide as two spaces
+			let indent_width = match formatter.options().indent_style {
+				IndentStyle::Tab => 2,
+				IndentStyle::Space(width) => width as usize,
+			};
+
+			let is_factory = name.starts_with(|c: char| c.is_ascii_uppercase())
+				|| name.chars().all(|c| c == '_' || c == '$');
+
+			is_factory || name.len() <= indent_width
+		}
+		_ => false,
+	}
+}
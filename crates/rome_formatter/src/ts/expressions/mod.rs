@@ -1,9 +1,60 @@
 mod array_expr;
 mod arrow_expr;
+mod assignment_expression;
+mod binary_expression;
 mod call_expression;
+mod computed_member_expression;
+mod conditional_expression;
 mod expression;
 mod literal_expression;
+mod member_chain;
+mod new_expression;
 mod object_expression;
+mod parentheses;
 mod reference_identifier_expression;
 mod sequence_expression;
+mod static_member_expression;
 mod super_expression;
+mod template;
+mod this_expression;
+
+pub(crate) use binary_expression::BinaryLikeExpression;
+pub(crate) use parentheses::{needs_parentheses, parent_without_parentheses, without_parentheses};
+pub(crate) use template::format_flat;
+
+use crate::{
+	format_elements, group_elements, indent, soft_line_break_or_space, space_token, FormatElement,
+	FormatResult, Formatter,
+};
+use rslint_parser::ast::JsAnyExpression;
+
+/// Formats the right side of an assignment or of an initializer, the part after the `=`.
+///
+/// Binary expressions and conditionals testing a binary expression move to the next line
+/// when they don't fit, everything else stays on the line of the `=` and breaks on its own.
+pub(crate) fn format_assignment_right(
+	right: JsAnyExpression,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let breaks_after_operator = match without_parentheses(right.clone()) {
+		JsAnyExpression::JsConditionalExpression(conditional) => {
+			let test = conditional.test().map(without_parentheses);
+			matches!(test.map(BinaryLikeExpression::cast), Ok(Some(test)) if !test.should_inline())
+		}
+		expression => matches!(
+			BinaryLikeExpression::cast(expression),
+			Some(binary) if !binary.should_inline()
+		),
+	};
+
+	let right = formatter.format_node(right)?;
+
+	if breaks_after_operator {
+		Ok(group_elements(indent(format_elements![
+			soft_line_break_or_space(),
+			right
+		])))
+	} else {
+		Ok(format_elements![space_token(), right])
+	}
+}
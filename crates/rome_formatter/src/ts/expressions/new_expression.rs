@@ -0,0 +1,28 @@
+use crate::{
+	empty_element, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::NewExpr;
+
+impl ToFormatElement for NewExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_args = match self.type_args() {
+			Some(type_args) => formatter.format_node(type_args)?,
+			None => empty_element(),
+		};
+
+		// `new a` calls the constructor without arguments, like `new a()`
+		let arguments = match self.arguments() {
+			Ok(arguments) => formatter.format_node(arguments)?,
+			Err(_) => token("()"),
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.new_token()?)?,
+			space_token(),
+			formatter.format_node(self.object()?)?,
+			type_args,
+			arguments
+		])
+	}
+}
@@ -1,18 +1,37 @@
+use crate::formatter::join_elements_keeping_empty_lines;
 use crate::{
-	format_elements, group_elements, if_group_breaks, join_elements, soft_indent,
-	soft_line_break_or_space, token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	format_elements, group_elements, indent, soft_line_break_or_space, FormatElement, FormatResult,
+	Formatter, ToFormatElement, TrailingSeparator,
 };
 use rslint_parser::ast::JsObjectExpression;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsObjectExpression {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let props = formatter.format_separated(self.members())?;
+		let l_curly = formatter.format_token(&self.l_curly_token()?)?;
+		let r_curly = formatter.format_token(&self.r_curly_token()?)?;
 
+		if self.members().is_empty() {
+			return Ok(format_elements![l_curly, r_curly]);
+		}
+
+		let props = formatter.format_separated(self.members(), TrailingSeparator::Es5)?;
+		let members = self
+			.members()
+			.iter()
+			.zip(props)
+			.map(|(member, formatted)| Ok((member?.syntax().clone(), formatted)))
+			.collect::<FormatResult<Vec<_>>>()?;
+
+		// `{ a: 1 }` has spaces inside the braces when it fits on a single line
 		Ok(group_elements(format_elements!(
-			formatter.format_token(&self.l_curly_token()?)?,
-			soft_indent(join_elements(soft_line_break_or_space(), props)),
-			if_group_breaks(token(",")),
-			formatter.format_token(&self.r_curly_token()?)?,
+			l_curly,
+			indent(format_elements![
+				soft_line_break_or_space(),
+				join_elements_keeping_empty_lines(soft_line_break_or_space(), members)
+			]),
+			soft_line_break_or_space(),
+			r_curly,
 		)))
 	}
 }
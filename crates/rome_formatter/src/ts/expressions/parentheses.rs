@@ -0,0 +1,329 @@
+//! Decides where the formatter prints parentheses around expressions.
+//!
+//! The parentheses of the source, the [JsParenthesizedExpression] nodes, are never printed as they are.
+//! Instead, an expression is wrapped in parentheses when removing them would change how the code is parsed,
+//! for example `(a + b) * c`, or when they make the code easier to read, for example `(a && b) || c`.
+
+use crate::ts::expressions::binary_expression::{precedence, should_flatten};
+use crate::{
+	empty_element, format_elements, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{JsAnyExpression, JsAnyLiteralExpression, JsParenthesizedExpression};
+use rslint_parser::{AstNode, SyntaxKind, SyntaxNode, T};
+
+impl ToFormatElement for JsParenthesizedExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		// The expression adds its own parentheses if it needs them, see [needs_parentheses]
+		Ok(format_elements![
+			formatter.format_replaced(&self.l_paren_token()?, empty_element()),
+			formatter.format_node(self.expression()?)?,
+			formatter.format_replaced(&self.r_paren_token()?, empty_element()),
+		])
+	}
+}
+
+/// Returns the expression without the parentheses around it, for example `a + b` for `((a + b))`
+pub(crate) fn without_parentheses(expression: JsAnyExpression) -> JsAnyExpression {
+	let mut current = expression;
+
+	while let JsAnyExpression::JsParenthesizedExpression(parenthesized) = &current {
+		match parenthesized.expression() {
+			Ok(expression) => current = expression,
+			Err(_) => break,
+		}
+	}
+
+	current
+}
+
+/// Returns the parent of the node, ignoring the parentheses around it
+pub(crate) fn parent_without_parentheses(node: &SyntaxNode) -> Option<SyntaxNode> {
+	node.ancestors()
+		.skip(1)
+		.find(|ancestor| ancestor.kind() != SyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+}
+
+/// Returns `true` if the expression must be printed in parentheses
+pub(crate) fn needs_parentheses(expression: &JsAnyExpression) -> bool {
+	let node = expression.syntax();
+
+	// `child` is the node that takes the place of the expression in its parent: it's the outermost
+	// parenthesized expression if the expression is in parentheses in the source
+	let mut child = node.clone();
+	let parent = loop {
+		match child.parent() {
+			Some(parent) if parent.kind() == SyntaxKind::JS_PARENTHESIZED_EXPRESSION => {
+				child = parent
+			}
+			Some(parent) => break parent,
+			None => return false,
+		}
+	};
+
+	let is_first_child = parent.first_child().as_ref() == Some(&child);
+
+	match expression {
+		JsAnyExpression::JsObjectExpression(_) => matches!(
+			leftmost_container_kind(node),
+			Some(SyntaxKind::JS_EXPRESSION_STATEMENT | SyntaxKind::JS_ARROW_FUNCTION_EXPRESSION)
+		),
+		JsAnyExpression::JsFunctionExpression(_) | JsAnyExpression::JsClassExpression(_) => {
+			let is_callee = is_first_child
+				&& matches!(
+					parent.kind(),
+					SyntaxKind::CALL_EXPR | SyntaxKind::NEW_EXPR | SyntaxKind::TEMPLATE
+				);
+
+			is_callee
+				|| matches!(
+					leftmost_container_kind(node),
+					Some(SyntaxKind::JS_EXPRESSION_STATEMENT | SyntaxKind::EXPORT_DEFAULT_EXPR)
+				)
+		}
+		JsAnyExpression::JsSequenceExpression(_) => !matches!(
+			parent.kind(),
+			SyntaxKind::JS_EXPRESSION_STATEMENT
+				| SyntaxKind::JS_SEQUENCE_EXPRESSION
+				| SyntaxKind::FOR_STMT_INIT
+				| SyntaxKind::FOR_STMT_UPDATE
+				| SyntaxKind::JS_RETURN_STATEMENT
+				| SyntaxKind::JS_THROW_STATEMENT
+		),
+		JsAnyExpression::JsAssignmentExpression(assignment) => {
+			let is_object_pattern = matches!(
+				assignment.left(),
+				Ok(left) if left.syntax().kind() == SyntaxKind::JS_OBJECT_ASSIGNMENT_TARGET
+			);
+
+			match parent.kind() {
+				// `({ a } = b)` would be a block otherwise
+				SyntaxKind::JS_EXPRESSION_STATEMENT => is_object_pattern,
+				SyntaxKind::JS_SEQUENCE_EXPRESSION
+				| SyntaxKind::FOR_STMT_INIT
+				| SyntaxKind::FOR_STMT_UPDATE
+				// `a = b = c`
+				| SyntaxKind::JS_ASSIGNMENT_EXPRESSION => false,
+				_ => true,
+			}
+		}
+		JsAnyExpression::JsArrowFunctionExpression(_)
+		| JsAnyExpression::JsConditionalExpression(_)
+		| JsAnyExpression::JsYieldExpression(_)
+		| JsAnyExpression::JsAwaitExpression(_) => {
+			let is_conditional_test =
+				is_first_child && parent.kind() == SyntaxKind::JS_CONDITIONAL_EXPRESSION;
+
+			is_conditional_test || is_operand(&parent) || is_left_hand_side(&parent, is_first_child)
+		}
+		JsAnyExpression::JsBinaryExpression(_) | JsAnyExpression::JsLogicalExpression(_) => {
+			binary_like_needs_parentheses(node, &parent, is_first_child)
+		}
+		JsAnyExpression::JsUnaryExpression(unary) => match parent.kind() {
+			// `+(+a)` and `-(-a)` would be the `++` and `--` operators
+			SyntaxKind::JS_UNARY_EXPRESSION => {
+				let operator = unary.operator().map(|operator| operator.kind());
+				let parent_operator = unary_operator(&parent);
+
+				matches!(operator, Ok(T![+] | T![-])) && operator.ok() == parent_operator
+			}
+			// `(-a) ** b` is a syntax error without the parentheses
+			SyntaxKind::JS_BINARY_EXPRESSION => {
+				is_first_child && binary_operator(&parent) == Some(T![**])
+			}
+			_ => is_left_hand_side(&parent, is_first_child),
+		},
+		JsAnyExpression::JsPreUpdateExpression(update) => match parent.kind() {
+			// `+(++a)` and `-(--a)`
+			SyntaxKind::JS_UNARY_EXPRESSION => {
+				let operator = update.operator().map(|operator| operator.kind());
+				let parent_operator = unary_operator(&parent);
+
+				matches!(
+					(parent_operator, operator),
+					(Some(T![+]), Ok(T![++])) | (Some(T![-]), Ok(T![--]))
+				)
+			}
+			SyntaxKind::JS_BINARY_EXPRESSION => {
+				is_first_child && binary_operator(&parent) == Some(T![**])
+			}
+			_ => is_left_hand_side(&parent, is_first_child),
+		},
+		JsAnyExpression::JsPostUpdateExpression(_) => is_left_hand_side(&parent, is_first_child),
+		JsAnyExpression::CallExpr(_)
+		| JsAnyExpression::JsStaticMemberExpression(_)
+		| JsAnyExpression::JsComputedMemberExpression(_)
+		| JsAnyExpression::TsNonNull(_) => {
+			// `new (a().b)()` calls the result of `new a()` otherwise
+			let is_new_callee = is_first_child && parent.kind() == SyntaxKind::NEW_EXPR;
+			if is_new_callee && chain_links(node).any(|link| link.kind() == SyntaxKind::CALL_EXPR) {
+				return true;
+			}
+
+			// `(a?.b).c` throws if `a` is nullish, but `a?.b.c` short-circuits the whole chain.
+			// The tree is the same without the parentheses, that's why the ones of the source are kept.
+			child != *node
+				&& is_left_hand_side(&parent, is_first_child)
+				&& !has_optional_chain_token(&parent)
+				&& chain_links(node).any(|link| has_optional_chain_token(&link))
+		}
+		// `(1).toString()`, the `.` would be the decimal point otherwise
+		JsAnyExpression::JsAnyLiteralExpression(
+			JsAnyLiteralExpression::JsNumberLiteralExpression(_),
+		) => is_first_child && parent.kind() == SyntaxKind::JS_STATIC_MEMBER_EXPRESSION,
+		JsAnyExpression::TsAssertion(_) | JsAnyExpression::TsConstAssertion(_) => {
+			is_operand(&parent) || is_left_hand_side(&parent, is_first_child)
+		}
+		_ => false,
+	}
+}
+
+/// Binary and logical expressions need parentheses when they're the operand of an operation with a
+/// higher precedence, `(a + b) * c`, or of the same precedence on the right side, `a - (b - c)`.
+fn binary_like_needs_parentheses(
+	node: &SyntaxNode,
+	parent: &SyntaxNode,
+	is_first_child: bool,
+) -> bool {
+	let operator = match binary_operator(node) {
+		Some(operator) => operator,
+		None => return false,
+	};
+
+	match parent.kind() {
+		SyntaxKind::JS_BINARY_EXPRESSION | SyntaxKind::JS_LOGICAL_EXPRESSION => {
+			let parent_operator = match binary_operator(parent) {
+				Some(operator) => operator,
+				None => return false,
+			};
+
+			let parent_precedence = precedence(parent_operator);
+			let precedence = precedence(operator);
+
+			let is_logical = |operator| matches!(operator, T![&&] | T![||] | T![??]);
+			let is_bitwise =
+				|operator| matches!(operator, T![&] | T![|] | T![^] | T![<<] | T![>>] | T![>>>]);
+
+			if parent_precedence > precedence {
+				return true;
+			}
+
+			if parent_precedence == precedence {
+				return !is_first_child || !should_flatten(parent_operator, operator);
+			}
+
+			// `(a && b) || c` reads better, and `??` can't be mixed with the other logical operators
+			if is_logical(parent_operator) && is_logical(operator) {
+				return true;
+			}
+
+			// `(a % b) + c`
+			if operator == T![%] {
+				return matches!(parent_operator, T![+] | T![-]);
+			}
+
+			is_bitwise(parent_operator)
+		}
+		_ => {
+			// `for ((a in b);;)`, the `in` would be the one of a `for...in` loop otherwise
+			if operator == T![in] && is_in_for_initializer(node) {
+				return true;
+			}
+
+			is_operand(parent) || is_left_hand_side(parent, is_first_child)
+		}
+	}
+}
+
+/// Whether the expression is the operand of a binary or unary operation
+fn is_operand(parent: &SyntaxNode) -> bool {
+	matches!(
+		parent.kind(),
+		SyntaxKind::JS_BINARY_EXPRESSION
+			| SyntaxKind::JS_LOGICAL_EXPRESSION
+			| SyntaxKind::JS_UNARY_EXPRESSION
+			| SyntaxKind::JS_AWAIT_EXPRESSION
+	)
+}
+
+/// Whether the expression is the object of a member expression, the callee of a call,
+/// or the tag of a template: the positions that only accept left hand side expressions
+fn is_left_hand_side(parent: &SyntaxNode, is_first_child: bool) -> bool {
+	is_first_child
+		&& matches!(
+			parent.kind(),
+			SyntaxKind::JS_STATIC_MEMBER_EXPRESSION
+				| SyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION
+				| SyntaxKind::CALL_EXPR
+				| SyntaxKind::NEW_EXPR
+				| SyntaxKind::TEMPLATE
+				| SyntaxKind::TS_NON_NULL
+		) || parent.kind() == SyntaxKind::JS_EXTENDS_CLAUSE
+}
+
+/// Returns the member, call and non-null expressions of the chain that ends with the node,
+/// `a().b` and `a()` for `a().b`
+fn chain_links(node: &SyntaxNode) -> impl Iterator<Item = SyntaxNode> {
+	std::iter::successors(Some(node.clone()), |link| link.first_child()).take_while(|link| {
+		matches!(
+			link.kind(),
+			SyntaxKind::JS_STATIC_MEMBER_EXPRESSION
+				| SyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION
+				| SyntaxKind::CALL_EXPR
+				| SyntaxKind::TS_NON_NULL
+		)
+	})
+}
+
+/// Whether the node is an optional member access or call, `a?.b`, `a?.[b]` or `a?.()`
+fn has_optional_chain_token(node: &SyntaxNode) -> bool {
+	node.children_with_tokens()
+		.any(|element| element.kind() == T![?.])
+}
+
+/// Returns the operator of a binary or logical expression
+fn binary_operator(node: &SyntaxNode) -> Option<SyntaxKind> {
+	match JsAnyExpression::cast(node.clone())? {
+		JsAnyExpression::JsBinaryExpression(binary) => binary.operator().ok(),
+		JsAnyExpression::JsLogicalExpression(logical) => logical.operator().ok(),
+		_ => None,
+	}
+	.map(|operator| operator.kind())
+}
+
+fn unary_operator(node: &SyntaxNode) -> Option<SyntaxKind> {
+	match JsAnyExpression::cast(node.clone())? {
+		JsAnyExpression::JsUnaryExpression(unary) => {
+			unary.operator().ok().map(|operator| operator.kind())
+		}
+		_ => None,
+	}
+}
+
+/// Returns the kind of the node that starts with the expression, going through the
+/// expressions it's the leftmost part of. For example, the object of `({}).toString();` is
+/// at the start of the expression statement.
+fn leftmost_container_kind(node: &SyntaxNode) -> Option<SyntaxKind> {
+	let mut current = node.clone();
+
+	loop {
+		let parent = current.parent()?;
+		let is_leftmost = parent.kind() == SyntaxKind::JS_PARENTHESIZED_EXPRESSION
+			|| JsAnyExpression::can_cast(parent.kind())
+				&& parent.kind() != SyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+				&& parent.text_trimmed_range().start() == current.text_trimmed_range().start();
+
+		if !is_leftmost {
+			return Some(parent.kind());
+		}
+
+		current = parent;
+	}
+}
+
+fn is_in_for_initializer(node: &SyntaxNode) -> bool {
+	node.ancestors()
+		.skip(1)
+		.find(|ancestor| !JsAnyExpression::can_cast(ancestor.kind()))
+		.map(|ancestor| ancestor.kind())
+		== Some(SyntaxKind::FOR_STMT_INIT)
+}
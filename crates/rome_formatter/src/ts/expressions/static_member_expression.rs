@@ -0,0 +1,43 @@
+use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::{
+	JsAnyReferenceMember, JsReferenceIdentifierMember, JsReferencePrivateMember,
+	JsStaticMemberExpression,
+};
+
+impl ToFormatElement for JsStaticMemberExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.object()?)?,
+			formatter.format_token(&self.operator()?)?,
+			formatter.format_node(self.member()?)?,
+		])
+	}
+}
+
+impl ToFormatElement for JsAnyReferenceMember {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyReferenceMember::JsReferenceIdentifierMember(member) => {
+				member.to_format_element(formatter)
+			}
+			JsAnyReferenceMember::JsReferencePrivateMember(member) => {
+				member.to_format_element(formatter)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for JsReferenceIdentifierMember {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.name_token()?)
+	}
+}
+
+impl ToFormatElement for JsReferencePrivateMember {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.hash_token()?)?,
+			formatter.format_token(&self.name_token()?)?,
+		])
+	}
+}
@@ -0,0 +1,125 @@
+use crate::format_element::{ConditionalGroupContent, GroupPrintMode, LineMode};
+use crate::format_json::tokenize_embedded_json;
+use crate::{
+	concat_elements, empty_element, format_elements, indent, join_elements, line_suffix_boundary,
+	space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{JsAnyExpression, Template, TemplateElement, TemplatePart};
+
+impl ToFormatElement for Template {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let tag = match self.tag() {
+			Some(tag) => formatter.format_node(tag)?,
+			None => empty_element(),
+		};
+
+		let type_args = match self.type_args() {
+			Some(type_args) => formatter.format_node(type_args)?,
+			None => empty_element(),
+		};
+		let tag = format_elements![tag, type_args];
+
+		let l_tick = formatter.format_token(&self.backtick_token()?)?;
+		let r_tick = formatter.format_token(&self.r_tick_token()?)?;
+
+		if let Some(json) = format_json_template(self, formatter) {
+			return Ok(format_elements![tag, l_tick, json, r_tick]);
+		}
+
+		let elements = self
+			.elements()
+			.map(|part| match part {
+				// The chunks are part of the string value, they're printed exactly as they are
+				TemplatePart::Chunk(chunk) => formatter.format_token(&chunk),
+				TemplatePart::Element(element) => element.to_format_element(formatter),
+			})
+			.collect::<FormatResult<Vec<_>>>()?;
+
+		Ok(format_elements![
+			tag,
+			l_tick,
+			concat_elements(elements),
+			r_tick
+		])
+	}
+}
+
+impl ToFormatElement for TemplateElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		// A line break inside `${}` looks like a line break of the string, the expression
+		// is printed on the line of the template even if it doesn't fit
+		let expression = format_flat(formatter.format_node(self.expression()?)?);
+
+		// A trailing line comment of the expression is printed before the `}`, not in the string
+		Ok(format_elements![
+			formatter.format_token(&self.dollar_curly_token()?)?,
+			expression,
+			line_suffix_boundary(),
+			formatter.format_token(&self.r_curly_token()?)?,
+		])
+	}
+}
+
+/// Formats the content of a template tagged with `json` if [crate::FormatOptions::format_embedded_json]
+/// is enabled. Returns `None` if the template isn't tagged with `json`, if it contains an
+/// expression, or if its content isn't a JSON document.
+fn format_json_template(template: &Template, formatter: &Formatter) -> Option<FormatElement> {
+	if !formatter.options().format_embedded_json {
+		return None;
+	}
+
+	let is_json_tag = match template.tag()? {
+		JsAnyExpression::JsReferenceIdentifierExpression(identifier) => {
+			matches!(identifier.name_token(), Ok(name) if name.text_trimmed() == "json")
+		}
+		_ => false,
+	};
+
+	if !is_json_tag {
+		return None;
+	}
+
+	let mut parts = template.elements();
+
+	let chunk = match (parts.next(), parts.next()) {
+		(Some(TemplatePart::Chunk(chunk)), None) => chunk,
+		_ => return None,
+	};
+
+	let json = tokenize_embedded_json(chunk.text_trimmed())?;
+
+	Some(formatter.format_replaced(&chunk, json))
+}
+
+/// Returns the element as it's printed when it fits on a single line: the soft line breaks are
+/// removed, or replaced by spaces, and the content printed only when a group breaks is dropped.
+///
+/// The hard line breaks are kept, with their indentation, for example the ones of the body of a function.
+pub(crate) fn format_flat(element: FormatElement) -> FormatElement {
+	match element {
+		FormatElement::Line(line) => match line.mode {
+			LineMode::SoftOrSpace => space_token(),
+			LineMode::Soft => empty_element(),
+			LineMode::Hard | LineMode::Empty => FormatElement::Line(line),
+		},
+		FormatElement::Indent(content) => indent(format_flat(*content.content)),
+		FormatElement::Group(group) => format_flat(*group.content),
+		FormatElement::ConditionalGroupContent(ConditionalGroupContent { content, mode }) => {
+			match mode {
+				GroupPrintMode::Flat => format_flat(*content),
+				GroupPrintMode::Multiline => empty_element(),
+			}
+		}
+		FormatElement::List(list) => concat_elements(list.iter().cloned().map(format_flat)),
+		FormatElement::Fill(fill) => join_elements(
+			format_flat(*fill.separator),
+			fill.list.iter().cloned().map(format_flat),
+		),
+		FormatElement::BestFitting(best_fitting) => best_fitting
+			.variants
+			.into_iter()
+			.next()
+			.map_or_else(empty_element, format_flat),
+		element => element,
+	}
+}
@@ -0,0 +1,8 @@
+use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::JsThisExpression;
+
+impl ToFormatElement for JsThisExpression {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.this_token()?)
+	}
+}
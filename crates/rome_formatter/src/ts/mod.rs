@@ -7,17 +7,36 @@ mod class;
 mod declarators;
 mod expressions;
 mod ident;
+mod module;
 mod name;
 mod object_members;
 mod parameter_list;
 mod script;
-mod statements;
+pub(crate) mod statements;
+mod types;
+
+pub(crate) use arg_list::should_hug_last_argument;
 
 #[cfg(test)]
 mod test {
-	use rslint_parser::parse_text;
+	use rslint_parser::{parse_text, parse_with_syntax, Syntax, TextRange, TextSize};
+
+	use crate::{FormatOptions, Formatter, QuoteStyle, TrailingComma};
+
+	/// Formats the statements covered by the range of `selection` in `src` and applies the edit
+	fn format_range(src: &str, selection: &str) -> String {
+		let start = TextSize::from(src.find(selection).unwrap() as u32);
+		let range = TextRange::at(start, TextSize::from(selection.len() as u32));
 
-	use crate::Formatter;
+		let tree = parse_text(src, 0);
+		let edit = Formatter::default()
+			.format_range(&tree.syntax(), range)
+			.unwrap();
+
+		let mut result = String::from(src);
+		edit.apply(&mut result);
+		result
+	}
 
 	#[test]
 	fn arrow_function() {
@@ -70,4 +89,203 @@ mod test {
 "#
 		);
 	}
+
+	#[test]
+	fn ts_import_equals_declaration() {
+		let src = "import  a=require( 'a' )\nexport import b = require(\"b\");";
+		let tree = parse_with_syntax(src, 0, Syntax::default().typescript());
+		let result = Formatter::default().format_root(&tree.syntax()).unwrap();
+		assert_eq!(
+			result.code(),
+			"import a = require(\"a\");\nexport import b = require(\"b\");\n"
+		);
+	}
+
+	#[test]
+	fn json_template() {
+		let src = "const a = json`{\"b\":[1,  2],\n\"c\":null}`;\nconst d = json`{ ${e} }`;";
+		let tree = parse_text(src, 0);
+		let options = FormatOptions {
+			format_embedded_json: true,
+			..FormatOptions::default()
+		};
+		let result = Formatter::new(options).format_root(&tree.syntax()).unwrap();
+		assert_eq!(
+			result.code(),
+			"const a = json`{\"b\": [1, 2], \"c\": null}`;\nconst d = json`{ ${e} }`;\n"
+		);
+
+		// The content of the template is left as it is when the option is disabled
+		let result = Formatter::default().format_root(&tree.syntax()).unwrap();
+		assert_eq!(
+			result.code(),
+			"const a = json`{\"b\":[1,  2],\n\"c\":null}`;\nconst d = json`{ ${e} }`;\n"
+		);
+	}
+
+	#[test]
+	fn trailing_comma() {
+		let src = "function foo(someLongParameterName, someOtherLongParameterName, ...yetAnotherLongParameterName) {}
+foo(someLongArgumentName, someOtherLongArgumentName, yetAnotherLongArgumentName, andMore);
+let a = [someLongElementName, someOtherLongElementName, yetAnotherLongElementName,];";
+		let tree = parse_text(src, 0);
+		let format = |trailing_comma| {
+			let options = FormatOptions {
+				trailing_comma,
+				..FormatOptions::default()
+			};
+			Formatter::new(options).format_root(&tree.syntax()).unwrap()
+		};
+
+		// No comma after a rest parameter
+		assert_eq!(
+			format(TrailingComma::All).code(),
+			"function foo(
+	someLongParameterName,
+	someOtherLongParameterName,
+	...yetAnotherLongParameterName
+) {}
+foo(
+	someLongArgumentName,
+	someOtherLongArgumentName,
+	yetAnotherLongArgumentName,
+	andMore,
+);
+let a = [
+	someLongElementName,
+	someOtherLongElementName,
+	yetAnotherLongElementName,
+];
+"
+		);
+
+		assert_eq!(
+			format(TrailingComma::None).code(),
+			"function foo(
+	someLongParameterName,
+	someOtherLongParameterName,
+	...yetAnotherLongParameterName
+) {}
+foo(
+	someLongArgumentName,
+	someOtherLongArgumentName,
+	yetAnotherLongArgumentName,
+	andMore
+);
+let a = [
+	someLongElementName,
+	someOtherLongElementName,
+	yetAnotherLongElementName
+];
+"
+		);
+	}
+
+	#[test]
+	fn single_quote_style() {
+		let src = r#"let a = "b" + "it's" + 'c\'d'; let o = { "key": a };"#;
+		let tree = parse_text(src, 0);
+		let options = FormatOptions {
+			quote_style: QuoteStyle::Single,
+			..FormatOptions::default()
+		};
+		let result = Formatter::new(options).format_root(&tree.syntax()).unwrap();
+		assert_eq!(
+			result.code(),
+			r#"let a = 'b' + "it's" + "c'd";
+let o = { 'key': a };
+"#
+		);
+	}
+
+	#[test]
+	fn range_formats_the_enclosing_statement() {
+		let src = "function f() {\n  if (a) {\n    b(  );\n  c( )\n  }\n}\nd( );\n";
+
+		assert_eq!(
+			format_range(src, "b("),
+			"function f() {\n  if (a) {\n    b();\n  c( )\n  }\n}\nd( );\n"
+		);
+		// The statement is indented at the level of the blocks enclosing it
+		assert_eq!(
+			format_range(src, "if"),
+			"function f() {\n  if (a) {\n\t\tb();\n\t\tc();\n\t}\n}\nd( );\n"
+		);
+	}
+
+	#[test]
+	fn range_indents_like_the_formatted_enclosing_nodes() {
+		// The method body is indented by the object and by the method
+		let src = "const o = { m() { a( ); b( ); } }";
+		assert_eq!(
+			format_range(src, "a( ); b"),
+			"const o = { m() { a();\n\t\tb(); } }"
+		);
+
+		let src = "namespace n {\n  function f() {\n    a( ); b( );\n  }\n}";
+		let start = TextSize::from(src.find("a(").unwrap() as u32);
+		let tree = parse_with_syntax(src, 0, Syntax::default().typescript());
+		let edit = Formatter::default()
+			.format_range(&tree.syntax(), TextRange::at(start, TextSize::from(8)))
+			.unwrap();
+		let mut result = String::from(src);
+		edit.apply(&mut result);
+		assert_eq!(
+			result,
+			"namespace n {\n  function f() {\n    a();\n\t\tb();\n  }\n}"
+		);
+	}
+
+	#[test]
+	fn range_indents_like_the_enclosing_arguments_and_clauses() {
+		// The hugged last argument isn't indented
+		assert_eq!(
+			format_range("foo(() => { a( ); b( ); });", "a( ); b"),
+			"foo(() => { a();\n\tb(); });"
+		);
+		assert_eq!(
+			format_range("foo([() => { b( ); c( ); }], a);", "b( ); c"),
+			"foo([() => { b();\n\t\t\tc(); }], a);"
+		);
+		assert_eq!(
+			format_range("switch (a) { case 1: b( ); c( ); }", "b( ); c"),
+			"switch (a) { case 1: b();\n\t\tc(); }"
+		);
+	}
+
+	#[test]
+	fn range_formats_the_selected_statements_of_a_list() {
+		let src = "a( );\n// comment\nb( ); // trailing\nc( );\n";
+
+		assert_eq!(
+			format_range(src, "a( );\n// comment\nb"),
+			"a();\n// comment\nb(); // trailing\nc( );\n"
+		);
+	}
+
+	#[test]
+	fn member_chain_maps_the_end_of_its_links() {
+		let src = "promise.then(a).catch(b).finally(c);";
+		let tree = parse_text(src, 0);
+		let result = Formatter::default().format_root(&tree.syntax()).unwrap();
+
+		// The ends of `promise.then` and `promise.then(a)`
+		for end in [12u32, 15] {
+			assert!(result
+				.mappings()
+				.iter()
+				.any(|mapping| mapping.source == TextSize::from(end)));
+		}
+	}
+
+	#[test]
+	fn suppression_without_reason() {
+		let src =
+			"// rome-ignore format\nlet a =  1;\n// rome-ignore format: aligned\nlet b =  2;\n";
+		let tree = parse_text(src, 0);
+		let result = Formatter::default().format_root(&tree.syntax()).unwrap();
+
+		assert_eq!(result.code(), src);
+		assert_eq!(result.diagnostics().len(), 1);
+	}
 }
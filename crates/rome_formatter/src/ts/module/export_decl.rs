@@ -0,0 +1,44 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{ExportDecl, JsAnyExportDeclaration};
+
+impl ToFormatElement for ExportDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_token = if let Some(type_token) = self.type_token() {
+			format_elements![space_token(), formatter.format_token(&type_token)?]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			type_token,
+			space_token(),
+			formatter.format_node(self.decl()?)?
+		])
+	}
+}
+
+impl ToFormatElement for JsAnyExportDeclaration {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			JsAnyExportDeclaration::JsFunctionDeclaration(decl) => {
+				decl.to_format_element(formatter)
+			}
+			JsAnyExportDeclaration::JsClassDeclaration(decl) => decl.to_format_element(formatter),
+			JsAnyExportDeclaration::JsVariableDeclarationStatement(decl) => {
+				decl.to_format_element(formatter)
+			}
+			JsAnyExportDeclaration::ExportNamed(export_named) => {
+				export_named.to_format_element(formatter)
+			}
+			JsAnyExportDeclaration::TsEnum(decl) => decl.to_format_element(formatter),
+			JsAnyExportDeclaration::TsTypeAliasDecl(decl) => decl.to_format_element(formatter),
+			JsAnyExportDeclaration::TsNamespaceDecl(decl) => decl.to_format_element(formatter),
+			JsAnyExportDeclaration::TsModuleDecl(decl) => decl.to_format_element(formatter),
+			JsAnyExportDeclaration::TsInterfaceDecl(decl) => decl.to_format_element(formatter),
+		}
+	}
+}
@@ -0,0 +1,31 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{DefaultDecl, ExportDefaultDecl};
+
+impl ToFormatElement for ExportDefaultDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let default_token = if let Some(default_token) = self.default_token() {
+			format_elements![space_token(), formatter.format_token(&default_token)?]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			default_token,
+			space_token(),
+			formatter.format_node(self.decl()?)?
+		])
+	}
+}
+
+impl ToFormatElement for DefaultDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			DefaultDecl::JsFunctionDeclaration(decl) => decl.to_format_element(formatter),
+			DefaultDecl::JsClassDeclaration(decl) => decl.to_format_element(formatter),
+		}
+	}
+}
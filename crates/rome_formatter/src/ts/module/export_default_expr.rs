@@ -0,0 +1,40 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{ExportDefaultExpr, JsAnyExpression};
+
+impl ToFormatElement for ExportDefaultExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let default_token = if let Some(default_token) = self.default_token() {
+			format_elements![space_token(), formatter.format_token(&default_token)?]
+		} else {
+			empty_element()
+		};
+
+		let expr = self.expr()?;
+
+		// `export default function () {}` and `export default class {}` are declarations,
+		// they aren't followed by a semicolon
+		let is_declaration = matches!(
+			expr,
+			JsAnyExpression::JsFunctionExpression(_) | JsAnyExpression::JsClassExpression(_)
+		);
+
+		let semicolon = match self.semicolon_token() {
+			Some(semicolon) if is_declaration => {
+				formatter.format_replaced(&semicolon, empty_element())
+			}
+			None if is_declaration => empty_element(),
+			semicolon => formatter.format_semicolon(self, semicolon),
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			default_token,
+			space_token(),
+			formatter.format_node(expr)?,
+			semicolon
+		])
+	}
+}
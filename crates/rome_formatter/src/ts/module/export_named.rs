@@ -0,0 +1,42 @@
+use super::format_specifiers;
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatError, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::ExportNamed;
+
+impl ToFormatElement for ExportNamed {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_token = if let Some(type_token) = self.type_token() {
+			format_elements![formatter.format_token(&type_token)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		let source = if let Some(from_token) = self.from_token() {
+			let source_token = self
+				.source_token()
+				.ok_or(FormatError::MissingRequiredChild)?;
+			format_elements![
+				space_token(),
+				formatter.format_token(&from_token)?,
+				space_token(),
+				formatter.format_string_literal(&source_token)
+			]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			type_token,
+			format_specifiers(
+				formatter,
+				&self.l_curly_token()?,
+				self.specifiers(),
+				&self.r_curly_token()?,
+			)?,
+			source,
+			formatter.format_semicolon(self, self.semicolon_token())
+		])
+	}
+}
@@ -0,0 +1,40 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatError, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::ExportWildcard;
+
+impl ToFormatElement for ExportWildcard {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_token = if let Some(type_token) = self.type_token() {
+			format_elements![space_token(), formatter.format_token(&type_token)?]
+		} else {
+			empty_element()
+		};
+
+		let alias = if let Some(as_token) = self.as_token() {
+			let ident = self.ident().ok_or(FormatError::MissingRequiredChild)?;
+			format_elements![
+				space_token(),
+				formatter.format_token(&as_token)?,
+				space_token(),
+				formatter.format_node(ident)?
+			]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			type_token,
+			space_token(),
+			formatter.format_token(&self.star_token()?)?,
+			alias,
+			space_token(),
+			formatter.format_token(&self.from_token()?)?,
+			space_token(),
+			formatter.format_string_literal(&self.source_token()?),
+			formatter.format_semicolon(self, self.semicolon_token())
+		])
+	}
+}
@@ -0,0 +1,23 @@
+use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::{ImportClause, ImportStringSpecifier};
+
+impl ToFormatElement for ImportClause {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			ImportClause::WildcardImport(wildcard_import) => {
+				wildcard_import.to_format_element(formatter)
+			}
+			ImportClause::NamedImports(named_imports) => named_imports.to_format_element(formatter),
+			ImportClause::JsIdentifierBinding(binding) => binding.to_format_element(formatter),
+			ImportClause::ImportStringSpecifier(specifier) => {
+				specifier.to_format_element(formatter)
+			}
+		}
+	}
+}
+
+impl ToFormatElement for ImportStringSpecifier {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(formatter.format_string_literal(&self.source_token()?))
+	}
+}
@@ -0,0 +1,51 @@
+use crate::{
+	empty_element, format_elements, join_elements, space_token, FormatElement, FormatResult,
+	Formatter, ToFormatElement, TrailingSeparator,
+};
+use rslint_parser::ast::ImportDecl;
+
+impl ToFormatElement for ImportDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_token = if let Some(type_token) = self.type_token() {
+			format_elements![formatter.format_token(&type_token)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		// `import "module"` has no `from` clause, the module is its only import clause
+		let source = if let Ok(from_token) = self.from_token() {
+			format_elements![
+				space_token(),
+				formatter.format_token(&from_token)?,
+				space_token(),
+				formatter.format_string_literal(&self.source_token()?)
+			]
+		} else {
+			empty_element()
+		};
+
+		let assertion = if let Some(assert_token) = self.assert_token() {
+			format_elements![
+				space_token(),
+				formatter.format_token(&assert_token)?,
+				space_token(),
+				formatter.format_node(self.asserted_object()?)?
+			]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.import_token()?)?,
+			space_token(),
+			type_token,
+			join_elements(
+				space_token(),
+				formatter.format_separated(self.imports(), TrailingSeparator::Disallowed)?
+			),
+			source,
+			assertion,
+			formatter.format_semicolon(self, self.semicolon_token())
+		])
+	}
+}
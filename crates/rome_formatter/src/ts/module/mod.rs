@@ -0,0 +1,51 @@
+use crate::{
+	format_elements, group_elements, indent, join_elements, soft_line_break_or_space,
+	FormatElement, FormatResult, Formatter, TrailingSeparator,
+};
+use rslint_parser::ast::{AstSeparatedList, Specifier};
+use rslint_parser::SyntaxToken;
+
+mod export_decl;
+mod export_default_decl;
+mod export_default_expr;
+mod export_named;
+mod export_wildcard;
+mod import_clause;
+mod import_decl;
+mod named_imports;
+mod specifier;
+mod ts_export_assignment;
+mod ts_import_equals_decl;
+mod ts_namespace_export_decl;
+mod wildcard_import;
+
+/// Formats the `{ a, b as c }` specifiers of a named import or export.
+///
+/// The specifiers are printed on a single line if they fit, otherwise each specifier is
+/// printed on its own line.
+fn format_specifiers(
+	formatter: &Formatter,
+	l_curly_token: &SyntaxToken,
+	specifiers: AstSeparatedList<Specifier>,
+	r_curly_token: &SyntaxToken,
+) -> FormatResult<FormatElement> {
+	let l_curly = formatter.format_token(l_curly_token)?;
+	let r_curly = formatter.format_token(r_curly_token)?;
+
+	if specifiers.is_empty() {
+		return Ok(format_elements![l_curly, r_curly]);
+	}
+
+	Ok(group_elements(format_elements![
+		l_curly,
+		indent(format_elements![
+			soft_line_break_or_space(),
+			join_elements(
+				soft_line_break_or_space(),
+				formatter.format_separated(specifiers, TrailingSeparator::Es5)?
+			),
+		]),
+		soft_line_break_or_space(),
+		r_curly,
+	]))
+}
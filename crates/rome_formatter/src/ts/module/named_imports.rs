@@ -0,0 +1,14 @@
+use super::format_specifiers;
+use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::NamedImports;
+
+impl ToFormatElement for NamedImports {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		format_specifiers(
+			formatter,
+			&self.l_curly_token()?,
+			self.specifiers(),
+			&self.r_curly_token()?,
+		)
+	}
+}
@@ -0,0 +1,17 @@
+use crate::{
+	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::TsExportAssignment;
+
+impl ToFormatElement for TsExportAssignment {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			formatter.format_token(&self.eq_token()?)?,
+			space_token(),
+			formatter.format_node(self.expr()?)?,
+			formatter.format_semicolon(self, self.semicolon_token())
+		])
+	}
+}
@@ -0,0 +1,47 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{TsExternalModuleRef, TsImportEqualsDecl, TsModuleRef};
+
+impl ToFormatElement for TsImportEqualsDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let export_token = if let Some(export_token) = self.export_token() {
+			format_elements![formatter.format_token(&export_token)?, space_token()]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			export_token,
+			formatter.format_token(&self.import_token()?)?,
+			space_token(),
+			formatter.format_node(self.ident()?)?,
+			space_token(),
+			formatter.format_token(&self.eq_token()?)?,
+			space_token(),
+			formatter.format_node(self.module()?)?,
+			formatter.format_semicolon(self, self.semicolon_token())
+		])
+	}
+}
+
+impl ToFormatElement for TsModuleRef {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsModuleRef::TsExternalModuleRef(module_ref) => module_ref.to_format_element(formatter),
+			TsModuleRef::TsEntityName(name) => name.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsExternalModuleRef {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.require_token()?)?,
+			formatter.format_token(&self.l_paren_token()?)?,
+			formatter.format_string_literal(&self.module_token()?),
+			formatter.format_token(&self.r_paren_token()?)?
+		])
+	}
+}
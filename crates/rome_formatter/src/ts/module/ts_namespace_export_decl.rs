@@ -0,0 +1,22 @@
+use crate::{
+	format_elements, space_token, FormatElement, FormatError, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::TsNamespaceExportDecl;
+
+impl ToFormatElement for TsNamespaceExportDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let ident = self.ident().ok_or(FormatError::MissingRequiredChild)?;
+
+		Ok(format_elements![
+			formatter.format_token(&self.export_token()?)?,
+			space_token(),
+			formatter.format_token(&self.as_token()?)?,
+			space_token(),
+			formatter.format_token(&self.namespace_token()?)?,
+			space_token(),
+			formatter.format_node(ident)?,
+			formatter.format_semicolon(self, self.semicolon_token())
+		])
+	}
+}
@@ -0,0 +1,26 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatError, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::WildcardImport;
+
+impl ToFormatElement for WildcardImport {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let alias = if let Some(as_token) = self.as_token() {
+			let ident = self.ident().ok_or(FormatError::MissingRequiredChild)?;
+			format_elements![
+				space_token(),
+				formatter.format_token(&as_token)?,
+				space_token(),
+				formatter.format_node(ident)?
+			]
+		} else {
+			empty_element()
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.star_token()?)?,
+			alias
+		])
+	}
+}
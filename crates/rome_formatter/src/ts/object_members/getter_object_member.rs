@@ -1,16 +1,23 @@
 use crate::{
-	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
 };
 use rslint_parser::ast::JsGetterObjectMember;
 
 impl ToFormatElement for JsGetterObjectMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let return_type = match self.return_type() {
+			Some(return_type) => formatter.format_node(return_type)?,
+			None => empty_element(),
+		};
+
 		Ok(format_elements![
 			formatter.format_token(&self.get_token()?)?,
 			space_token(),
 			formatter.format_node(self.name()?)?,
 			formatter.format_token(&self.l_paren_token()?)?,
 			formatter.format_token(&self.r_paren_token()?)?,
+			return_type,
 			space_token(),
 			formatter.format_node(self.body()?)?
 		])
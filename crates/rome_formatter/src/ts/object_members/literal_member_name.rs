@@ -1,8 +1,15 @@
 use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsLiteralMemberName;
+use rslint_parser::SyntaxKind;
 
 impl ToFormatElement for JsLiteralMemberName {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		formatter.format_token(&self.value()?)
+		let value = self.value()?;
+
+		if value.kind() == SyntaxKind::JS_STRING_LITERAL {
+			Ok(formatter.format_string_literal(&value))
+		} else {
+			formatter.format_token(&value)
+		}
 	}
 }
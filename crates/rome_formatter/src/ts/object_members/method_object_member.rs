@@ -0,0 +1,40 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::JsMethodObjectMember;
+
+impl ToFormatElement for JsMethodObjectMember {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let async_token = match self.async_token() {
+			Some(token) => format_elements![formatter.format_token(&token)?, space_token()],
+			None => empty_element(),
+		};
+
+		let star = match self.star_token() {
+			Some(star) => formatter.format_token(&star)?,
+			None => empty_element(),
+		};
+
+		let type_params = match self.type_params() {
+			Some(type_params) => formatter.format_node(type_params)?,
+			None => empty_element(),
+		};
+
+		let return_type = match self.return_type() {
+			Some(return_type) => formatter.format_node(return_type)?,
+			None => empty_element(),
+		};
+
+		Ok(format_elements![
+			async_token,
+			star,
+			formatter.format_node(self.name()?)?,
+			type_params,
+			formatter.format_node(self.parameter_list()?)?,
+			return_type,
+			space_token(),
+			formatter.format_node(self.body()?)?
+		])
+	}
+}
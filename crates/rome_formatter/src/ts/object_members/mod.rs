@@ -1,6 +1,7 @@
 mod getter_object_member;
 mod ident_prop;
 mod literal_member_name;
+mod method_object_member;
 mod object_member;
 mod object_member_name;
 mod property_object_member;
@@ -1,5 +1,6 @@
 use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
 use rslint_parser::ast::JsAnyObjectMember;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsAnyObjectMember {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -13,8 +14,10 @@ impl ToFormatElement for JsAnyObjectMember {
 			JsAnyObjectMember::JsShorthandPropertyObjectMember(ident) => {
 				ident.to_format_element(formatter)
 			}
-			JsAnyObjectMember::JsMethodObjectMember(_) => todo!(),
-			JsAnyObjectMember::JsUnknownMember(_) => todo!(),
+			JsAnyObjectMember::JsMethodObjectMember(method) => method.to_format_element(formatter),
+			JsAnyObjectMember::JsUnknownMember(unknown_member) => {
+				Ok(formatter.format_verbatim(unknown_member.syntax()))
+			}
 		}
 	}
 }
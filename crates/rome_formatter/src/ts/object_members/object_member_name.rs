@@ -1,11 +1,14 @@
 use rslint_parser::ast::JsAnyObjectMemberName;
 
 use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsAnyObjectMemberName {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		match self {
-			JsAnyObjectMemberName::JsComputedMemberName(_) => todo!(),
+			JsAnyObjectMemberName::JsComputedMemberName(node) => {
+				Ok(formatter.format_unsupported(node.syntax()))
+			}
 			JsAnyObjectMemberName::JsLiteralMemberName(ident) => ident.to_format_element(formatter),
 		}
 	}
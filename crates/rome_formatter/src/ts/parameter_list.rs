@@ -1,12 +1,12 @@
 use crate::{
 	format_elements, group_elements, join_elements, soft_indent, soft_line_break_or_space,
-	FormatElement, FormatResult, Formatter, ToFormatElement,
+	FormatElement, FormatResult, Formatter, ToFormatElement, TrailingSeparator,
 };
-use rslint_parser::ast::{JsAnyParameter, JsParameterList};
+use rslint_parser::ast::{JsAnyParameter, JsParameterList, JsRestParameter};
 
 impl ToFormatElement for JsParameterList {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
-		let param_tokens = formatter.format_separated(self.parameters())?;
+		let param_tokens = formatter.format_separated(self.parameters(), TrailingSeparator::All)?;
 
 		Ok(group_elements(format_elements![
 			formatter.format_token(&self.l_paren_token()?)?,
@@ -21,7 +21,16 @@ impl ToFormatElement for JsAnyParameter {
 		match self {
 			JsAnyParameter::JsAnyBinding(binding) => binding.to_format_element(formatter),
 			JsAnyParameter::JsBindingWithDefault(binding) => binding.to_format_element(formatter),
-			JsAnyParameter::JsRestParameter(_) => todo!("rest parameter"),
+			JsAnyParameter::JsRestParameter(rest) => rest.to_format_element(formatter),
 		}
 	}
 }
+
+impl ToFormatElement for JsRestParameter {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.dotdotdot_token()?)?,
+			formatter.format_node(self.binding()?)?
+		])
+	}
+}
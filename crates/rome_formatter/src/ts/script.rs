@@ -3,6 +3,7 @@ use crate::{
 	format_elements, hard_line_break, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::JsRoot;
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsRoot {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -15,6 +16,11 @@ impl ToFormatElement for JsRoot {
 
 		elements.push(format_statements(self.statements(), formatter));
 
+		// The comments at the end of the file are in the leading trivia of the EOF token
+		if let Some(eof) = self.syntax().last_token() {
+			elements.push(formatter.format_token(&eof)?);
+		}
+
 		Ok(format_elements![
 			concat_elements(elements),
 			hard_line_break()
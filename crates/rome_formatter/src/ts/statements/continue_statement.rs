@@ -1,5 +1,5 @@
 use crate::{
-	empty_element, format_elements, space_token, token, FormatElement, FormatResult, Formatter,
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
 	ToFormatElement,
 };
 use rslint_parser::ast::JsContinueStatement;
@@ -15,7 +15,7 @@ impl ToFormatElement for JsContinueStatement {
 		Ok(format_elements![
 			formatter.format_token(&self.continue_token()?)?,
 			label,
-			token(";")
+			formatter.format_semicolon(self, self.semicolon_token())
 		])
 	}
 }
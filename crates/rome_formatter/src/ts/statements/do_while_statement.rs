@@ -1,5 +1,5 @@
 use crate::{
-	format_elements, group_elements, soft_indent, space_token, token, FormatElement, FormatResult,
+	format_elements, group_elements, soft_indent, space_token, FormatElement, FormatResult,
 	Formatter, ToFormatElement,
 };
 use rslint_parser::ast::JsDoWhileStatement;
@@ -18,7 +18,7 @@ impl ToFormatElement for JsDoWhileStatement {
 				soft_indent(formatter.format_node(self.test()?)?),
 				formatter.format_token(&self.r_paren_token()?)?
 			]),
-			token(";")
+			formatter.format_semicolon(self, self.semicolon_token())
 		])
 	}
 }
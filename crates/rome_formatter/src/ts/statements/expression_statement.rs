@@ -1,12 +1,103 @@
-use rslint_parser::ast::JsExpressionStatement;
+use rslint_parser::ast::{JsAnyExpression, JsExpressionStatement};
+use rslint_parser::{AstNode, SyntaxKind, T};
 
-use crate::{format_elements, token, FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::ts::expressions::{needs_parentheses, without_parentheses};
+use crate::{
+	concat_elements, format_elements, group_elements, indent, token, FormatElement, FormatResult,
+	Formatter, Semicolons, ToFormatElement,
+};
 
 impl ToFormatElement for JsExpressionStatement {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let expression = self.expression()?;
+		let starts_with_hazard = formatter.options().semicolons == Semicolons::AsNeeded
+			&& starts_with_asi_hazard(expression.clone());
+
+		let mut formatted = formatter.format_node(expression)?;
+
+		// Without semicolons, `a\n(b)` is the call `a(b)`: the statement starts with a semicolon
+		// so that it doesn't continue the previous one
+		if starts_with_hazard {
+			formatted = insert_before_source_marker(&formatted, &mut Some(token(";")));
+		}
+
 		Ok(format_elements![
-			formatter.format_node(self.expression()?)?,
-			token(";")
+			formatted,
+			formatter.format_semicolon(self, self.semicolon_token())
 		])
 	}
 }
+
+/// Inserts `prefix` before the first source marker of `element`, the one of the first token.
+/// The leading comments of the token are printed before its marker, so they stay before the prefix.
+fn insert_before_source_marker(
+	element: &FormatElement,
+	prefix: &mut Option<FormatElement>,
+) -> FormatElement {
+	match element {
+		FormatElement::SourceMarker(_) => match prefix.take() {
+			Some(prefix) => format_elements![prefix, element.clone()],
+			None => element.clone(),
+		},
+		FormatElement::List(list) => concat_elements(
+			list.iter()
+				.map(|child| insert_before_source_marker(child, prefix))
+				.collect::<Vec<_>>(),
+		),
+		FormatElement::Group(group) => {
+			group_elements(insert_before_source_marker(&group.content, prefix))
+		}
+		FormatElement::Indent(content) => {
+			indent(insert_before_source_marker(&content.content, prefix))
+		}
+		_ => element.clone(),
+	}
+}
+
+/// Returns `true` if the formatted expression starts with a token that continues the previous
+/// statement when it isn't terminated by a semicolon: `(`, `[`, `` ` ``, `+`, `-`, `/` or a regex
+fn starts_with_asi_hazard(expression: JsAnyExpression) -> bool {
+	let mut current = without_parentheses(expression);
+
+	loop {
+		// The parentheses of the source aren't printed, only the ones the formatter adds
+		if needs_parentheses(&current) {
+			return true;
+		}
+
+		match &current {
+			// The parameters of an arrow function are always printed in parentheses
+			JsAnyExpression::JsArrowFunctionExpression(arrow) => {
+				return arrow.async_token().is_none() && arrow.type_parameters().is_none()
+			}
+			JsAnyExpression::JsAnyLiteralExpression(_) => {
+				return current.syntax().kind() == SyntaxKind::JS_REGEX_LITERAL_EXPRESSION
+			}
+			_ => {}
+		}
+
+		// The object of a member expression, the callee of a call, the left side of a binary...
+		let start = current.syntax().text_trimmed_range().start();
+		let leftmost = current
+			.syntax()
+			.first_child()
+			.filter(|child| child.text_trimmed_range().start() == start)
+			.and_then(JsAnyExpression::cast);
+
+		match leftmost {
+			Some(leftmost) => current = without_parentheses(leftmost),
+			None => {
+				let first_token = current.syntax().first_token().map(|token| token.kind());
+
+				return matches!(
+					first_token,
+					Some(
+						T!['(']
+							| T!['['] | T!['`'] | T![+] | T![-]
+							| T![++] | T![--] | T![/] | T![/=]
+					)
+				);
+			}
+		}
+	}
+}
@@ -1,6 +1,11 @@
-use crate::{hard_line_break, join_elements, FormatElement, Formatter};
-use rslint_parser::ast::{AstNodeList, JsAnyStatement};
-use rslint_parser::AstNode;
+use crate::formatter::join_elements_keeping_empty_lines;
+use crate::ts::expressions::{without_parentheses, BinaryLikeExpression};
+use crate::{
+	format_elements, group_elements, hard_line_break, if_group_breaks, soft_indent, token,
+	FormatElement, FormatResult, Formatter,
+};
+use rslint_parser::ast::{AstNodeList, JsAnyExpression, JsAnyStatement};
+use rslint_parser::{AstNode, SyntaxNode};
 
 mod block;
 mod break_statement;
@@ -26,12 +31,47 @@ pub fn format_statements(
 	stmts: AstNodeList<JsAnyStatement>,
 	formatter: &Formatter,
 ) -> FormatElement {
-	join_elements(
+	join_elements_keeping_empty_lines(
 		hard_line_break(),
-		stmts.iter().map(|stmt| {
-			formatter
-				.format_node(stmt.clone())
-				.unwrap_or_else(|_| formatter.format_raw(stmt.syntax()).trim_start().trim_end())
+		stmts.syntax_nodes().map(|node| {
+			let formatted = format_statement(&node, formatter);
+			(node, formatted)
 		}),
 	)
 }
+
+/// Formats a node of a list of statements.
+///
+/// The statements that can't be formatted, for example because of syntax errors,
+/// are printed as they are in the source code
+pub(crate) fn format_statement(node: &SyntaxNode, formatter: &Formatter) -> FormatElement {
+	JsAnyStatement::cast(node.clone())
+		.and_then(|stmt| formatter.format_node(stmt).ok())
+		.unwrap_or_else(|| formatter.format_verbatim(node))
+}
+
+/// Formats the argument of a `return` or `throw` statement.
+///
+/// A binary expression that doesn't fit on the line is wrapped in parentheses so that
+/// its operands can be indented on the following lines.
+pub(crate) fn format_return_or_throw_argument(
+	argument: JsAnyExpression,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let is_binary_like = matches!(
+		BinaryLikeExpression::cast(without_parentheses(argument.clone())),
+		Some(binary) if !binary.should_inline()
+	);
+
+	let argument = formatter.format_node(argument)?;
+
+	if is_binary_like {
+		Ok(group_elements(format_elements![
+			if_group_breaks(token("(")),
+			soft_indent(argument),
+			if_group_breaks(token(")"))
+		]))
+	} else {
+		Ok(argument)
+	}
+}
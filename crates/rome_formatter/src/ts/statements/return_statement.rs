@@ -1,5 +1,6 @@
+use crate::ts::statements::format_return_or_throw_argument;
 use crate::{
-	concat_elements, space_token, token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	concat_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::JsReturnStatement;
 
@@ -9,10 +10,10 @@ impl ToFormatElement for JsReturnStatement {
 
 		if let Some(argument) = self.argument() {
 			tokens.push(space_token());
-			tokens.push(formatter.format_node(argument)?);
+			tokens.push(format_return_or_throw_argument(argument, formatter)?);
 		}
 
-		tokens.push(token(";"));
+		tokens.push(formatter.format_semicolon(self, self.semicolon_token()));
 
 		Ok(concat_elements(tokens))
 	}
@@ -48,32 +48,44 @@ impl ToFormatElement for JsAnyStatement {
 				debugger_statement.to_format_element(formatter)
 			}
 
-			JsAnyStatement::ForOfStmt(_) => todo!(),
+			JsAnyStatement::ForOfStmt(node) => Ok(formatter.format_unsupported(node.syntax())),
 			JsAnyStatement::JsFunctionDeclaration(decl) => decl.to_format_element(formatter),
 			JsAnyStatement::JsClassDeclaration(decl) => decl.to_format_element(formatter),
 			JsAnyStatement::JsVariableDeclarationStatement(decl) => {
 				decl.to_format_element(formatter)
 			}
 			JsAnyStatement::JsUnknownStatement(unknown_statement) => {
-				Ok(formatter.format_raw(unknown_statement.syntax()))
-			}
-			JsAnyStatement::ImportDecl(_) => todo!(),
-			JsAnyStatement::ExportNamed(_) => todo!(),
-			JsAnyStatement::ExportDefaultDecl(_) => todo!(),
-			JsAnyStatement::ExportDefaultExpr(_) => todo!(),
-			JsAnyStatement::ExportWildcard(_) => todo!(),
-			JsAnyStatement::ExportDecl(_) => todo!(),
-			JsAnyStatement::TsImportEqualsDecl(_) => todo!(),
-			JsAnyStatement::TsExportAssignment(_) => todo!(),
-			JsAnyStatement::TsNamespaceExportDecl(_) => todo!(),
+				Ok(formatter.format_verbatim(unknown_statement.syntax()))
+			}
+			JsAnyStatement::ImportDecl(import_decl) => import_decl.to_format_element(formatter),
+			JsAnyStatement::ExportNamed(export_named) => export_named.to_format_element(formatter),
+			JsAnyStatement::ExportDefaultDecl(export_default_decl) => {
+				export_default_decl.to_format_element(formatter)
+			}
+			JsAnyStatement::ExportDefaultExpr(export_default_expr) => {
+				export_default_expr.to_format_element(formatter)
+			}
+			JsAnyStatement::ExportWildcard(export_wildcard) => {
+				export_wildcard.to_format_element(formatter)
+			}
+			JsAnyStatement::ExportDecl(export_decl) => export_decl.to_format_element(formatter),
+			JsAnyStatement::TsImportEqualsDecl(import_equals_decl) => {
+				import_equals_decl.to_format_element(formatter)
+			}
+			JsAnyStatement::TsExportAssignment(export_assignment) => {
+				export_assignment.to_format_element(formatter)
+			}
+			JsAnyStatement::TsNamespaceExportDecl(namespace_export_decl) => {
+				namespace_export_decl.to_format_element(formatter)
+			}
 			JsAnyStatement::JsTryFinallyStatement(try_finally) => {
 				try_finally.to_format_element(formatter)
 			}
-			JsAnyStatement::TsEnum(_) => todo!(),
-			JsAnyStatement::TsTypeAliasDecl(_) => todo!(),
-			JsAnyStatement::TsNamespaceDecl(_) => todo!(),
-			JsAnyStatement::TsModuleDecl(_) => todo!(),
-			JsAnyStatement::TsInterfaceDecl(_) => todo!(),
+			JsAnyStatement::TsEnum(decl) => decl.to_format_element(formatter),
+			JsAnyStatement::TsTypeAliasDecl(decl) => decl.to_format_element(formatter),
+			JsAnyStatement::TsNamespaceDecl(decl) => decl.to_format_element(formatter),
+			JsAnyStatement::TsModuleDecl(decl) => decl.to_format_element(formatter),
+			JsAnyStatement::TsInterfaceDecl(decl) => decl.to_format_element(formatter),
 		}
 	}
 }
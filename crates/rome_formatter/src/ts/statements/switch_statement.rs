@@ -1,10 +1,12 @@
+use crate::formatter::join_elements_keeping_empty_lines;
 use crate::ts::statements::format_statements;
 use crate::FormatResult;
 use crate::{
 	block_indent, format_element::indent, format_elements, group_elements, hard_line_break,
-	join_elements, soft_indent, space_token, FormatElement, Formatter, ToFormatElement,
+	soft_indent, space_token, FormatElement, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{JsAnySwitchClause, JsCaseClause, JsDefaultClause, JsSwitchStatement};
+use rslint_parser::AstNode;
 
 impl ToFormatElement for JsSwitchStatement {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -19,9 +21,12 @@ impl ToFormatElement for JsSwitchStatement {
 			space_token(),
 			group_elements(format_elements![
 				formatter.format_token(&self.l_curly_token()?)?,
-				block_indent(join_elements(
+				block_indent(join_elements_keeping_empty_lines(
 					hard_line_break(),
-					formatter.format_nodes(self.cases())?
+					self.cases()
+						.iter()
+						.map(|case| Ok((case.syntax().clone(), formatter.format_node(case)?)))
+						.collect::<FormatResult<Vec<_>>>()?
 				)),
 				formatter.format_token(&self.r_curly_token()?)?
 			])
@@ -1,17 +1,18 @@
+use crate::ts::statements::format_return_or_throw_argument;
 use crate::{
-	format_elements, space_token, token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
 };
 use rslint_parser::ast::JsThrowStatement;
 
 impl ToFormatElement for JsThrowStatement {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
 		let throw_token = formatter.format_token(&self.throw_token()?)?;
-		let exception = formatter.format_node(self.argument()?)?;
+		let exception = format_return_or_throw_argument(self.argument()?, formatter)?;
 		Ok(format_elements![
 			throw_token,
 			space_token(),
 			exception,
-			token(";")
+			formatter.format_semicolon(self, self.semicolon_token())
 		])
 	}
 }
@@ -0,0 +1,125 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatError, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	TsInfer, TsLiteral, TsParen, TsThis, TsType, TsTypeOperator, TsTypeQuery, TsTypeQueryExpr,
+};
+use rslint_parser::{AstNode, SyntaxNode};
+
+impl ToFormatElement for TsType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsType::TsAny(_)
+			| TsType::TsUnknown(_)
+			| TsType::TsNumber(_)
+			| TsType::TsObject(_)
+			| TsType::TsBoolean(_)
+			| TsType::TsBigint(_)
+			| TsType::TsString(_)
+			| TsType::TsSymbol(_)
+			| TsType::TsVoid(_)
+			| TsType::TsUndefined(_)
+			| TsType::TsNull(_)
+			| TsType::TsNever(_) => format_keyword_type(self.syntax(), formatter),
+			TsType::TsThis(ty) => ty.to_format_element(formatter),
+			TsType::TsLiteral(ty) => ty.to_format_element(formatter),
+			TsType::TsPredicate(ty) => ty.to_format_element(formatter),
+			TsType::TsTuple(ty) => ty.to_format_element(formatter),
+			TsType::TsParen(ty) => ty.to_format_element(formatter),
+			TsType::TsTypeRef(ty) => ty.to_format_element(formatter),
+			TsType::TsTemplate(ty) => ty.to_format_element(formatter),
+			TsType::TsMappedType(ty) => ty.to_format_element(formatter),
+			TsType::TsImport(ty) => ty.to_format_element(formatter),
+			TsType::TsArray(ty) => ty.to_format_element(formatter),
+			TsType::TsIndexedArray(ty) => ty.to_format_element(formatter),
+			TsType::TsTypeOperator(ty) => ty.to_format_element(formatter),
+			TsType::TsIntersection(ty) => ty.to_format_element(formatter),
+			TsType::TsUnion(ty) => ty.to_format_element(formatter),
+			TsType::TsFnType(ty) => ty.to_format_element(formatter),
+			TsType::TsConstructorType(ty) => ty.to_format_element(formatter),
+			TsType::TsConditionalType(ty) => ty.to_format_element(formatter),
+			TsType::TsObjectType(ty) => ty.to_format_element(formatter),
+			TsType::TsInfer(ty) => ty.to_format_element(formatter),
+			TsType::TsTypeQuery(ty) => ty.to_format_element(formatter),
+		}
+	}
+}
+
+/// Formats a type made of a single keyword, for example `string` or `void`
+fn format_keyword_type(node: &SyntaxNode, formatter: &Formatter) -> FormatResult<FormatElement> {
+	let keyword = node
+		.first_token()
+		.ok_or(FormatError::MissingRequiredChild)?;
+
+	formatter.format_token(&keyword)
+}
+
+impl ToFormatElement for TsThis {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.this_token()?)
+	}
+}
+
+impl ToFormatElement for TsLiteral {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let minus = match self.minus_token() {
+			Some(minus) => formatter.format_token(&minus)?,
+			None => empty_element(),
+		};
+
+		Ok(format_elements![
+			minus,
+			formatter.format_node(self.literal()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsParen {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.l_paren_token()?)?,
+			formatter.format_node(self.ty()?)?,
+			formatter.format_token(&self.r_paren_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeOperator {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.operator_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsInfer {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.infer_token()?)?,
+			space_token(),
+			formatter.format_node(self.name()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeQuery {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.typeof_token()?)?,
+			space_token(),
+			formatter.format_node(self.expr()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTypeQueryExpr {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsTypeQueryExpr::TsEntityName(name) => name.to_format_element(formatter),
+			TsTypeQueryExpr::TsImport(import) => import.to_format_element(formatter),
+		}
+	}
+}
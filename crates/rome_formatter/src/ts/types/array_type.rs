@@ -0,0 +1,23 @@
+use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::{TsArray, TsIndexedArray};
+
+impl ToFormatElement for TsArray {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.ty()?)?,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_token(&self.r_brack_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsIndexedArray {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.ty()?)?,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_node(self.index_type()?)?,
+			formatter.format_token(&self.r_brack_token()?)?
+		])
+	}
+}
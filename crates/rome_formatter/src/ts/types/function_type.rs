@@ -0,0 +1,45 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatError, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{TsConstructorType, TsFnType};
+
+impl ToFormatElement for TsFnType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_parameters = match self.type_parameters() {
+			Some(type_parameters) => formatter.format_node(type_parameters)?,
+			None => empty_element(),
+		};
+
+		let return_type = self
+			.return_type()
+			.ok_or(FormatError::MissingRequiredChild)?;
+
+		Ok(format_elements![
+			type_parameters,
+			formatter.format_node(self.params()?)?,
+			space_token(),
+			formatter.format_token(&self.fat_arrow_token()?)?,
+			space_token(),
+			formatter.format_node(return_type)?
+		])
+	}
+}
+
+impl ToFormatElement for TsConstructorType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let return_type = self
+			.return_type()
+			.ok_or(FormatError::MissingRequiredChild)?;
+
+		Ok(format_elements![
+			formatter.format_token(&self.new_token()?)?,
+			space_token(),
+			formatter.format_node(self.params()?)?,
+			space_token(),
+			formatter.format_token(&self.fat_arrow_token()?)?,
+			space_token(),
+			formatter.format_node(return_type)?
+		])
+	}
+}
@@ -0,0 +1,36 @@
+use crate::{
+	empty_element, format_elements, group_elements, indent, soft_line_break_or_space, space_token,
+	FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::TsIntersection;
+
+impl ToFormatElement for TsIntersection {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let mut amps = self.amp_tokens().into_iter();
+		let mut types = formatter.format_nodes(self.types())?;
+
+		let first = types.next().unwrap_or_else(empty_element);
+		let rest = types
+			.map(|ty| {
+				format_elements![
+					space_token(),
+					formatter.format_or_insert_token(amps.next(), "&"),
+					soft_line_break_or_space(),
+					ty
+				]
+			})
+			.collect::<Vec<_>>();
+
+		// The leading `&` of `type A = & B & C` is removed
+		let leading_amp = match self.leading_amp_token() {
+			Some(amp) => formatter.format_replaced(&amp, empty_element()),
+			None => empty_element(),
+		};
+
+		Ok(group_elements(format_elements![
+			leading_amp,
+			first,
+			indent(concat_elements(rest))
+		]))
+	}
+}
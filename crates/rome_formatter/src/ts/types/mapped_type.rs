@@ -0,0 +1,98 @@
+use crate::{
+	empty_element, format_elements, group_elements, if_group_breaks, indent,
+	soft_line_break_or_space, space_token, token, FormatElement, FormatError, FormatResult,
+	Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{TsMappedType, TsMappedTypeParam, TsMappedTypeReadonly};
+
+impl ToFormatElement for TsMappedType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let readonly = match self.readonly_modifier() {
+			Some(readonly) => format_elements![formatter.format_node(readonly)?, space_token()],
+			None => empty_element(),
+		};
+
+		let modifier = match self.minus_token().or_else(|| self.plus_token()) {
+			Some(modifier) => formatter.format_token(&modifier)?,
+			None => empty_element(),
+		};
+
+		let question_mark = match self.question_mark_token() {
+			Some(question_mark) => formatter.format_token(&question_mark)?,
+			None => empty_element(),
+		};
+
+		let semicolon = if_group_breaks(token(";"));
+		let semicolon = match self.semicolon_token() {
+			Some(semicolon_token) => formatter.format_replaced(&semicolon_token, semicolon),
+			None => semicolon,
+		};
+
+		Ok(group_elements(format_elements![
+			formatter.format_token(&self.l_curly_token()?)?,
+			indent(format_elements![
+				soft_line_break_or_space(),
+				readonly,
+				formatter.format_node(self.param()?)?,
+				modifier,
+				question_mark,
+				formatter.format_token(&self.colon_token()?)?,
+				space_token(),
+				formatter.format_node(self.ty()?)?,
+				semicolon
+			]),
+			soft_line_break_or_space(),
+			formatter.format_token(&self.r_curly_token()?)?
+		]))
+	}
+}
+
+impl ToFormatElement for TsMappedTypeReadonly {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let modifier = match self.minus_token().or_else(|| self.plus_token()) {
+			Some(modifier) => formatter.format_token(&modifier)?,
+			None => empty_element(),
+		};
+
+		let readonly = self
+			.readonly_token()
+			.ok_or(FormatError::MissingRequiredChild)?;
+
+		Ok(format_elements![
+			modifier,
+			formatter.format_token(&readonly)?
+		])
+	}
+}
+
+impl ToFormatElement for TsMappedTypeParam {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let l_brack = self
+			.l_brack_token()
+			.ok_or(FormatError::MissingRequiredChild)?;
+		let r_brack = self
+			.r_brack_token()
+			.ok_or(FormatError::MissingRequiredChild)?;
+
+		let alias = match (self.as_token(), self.alias()) {
+			(Some(as_token), Some(alias)) => format_elements![
+				space_token(),
+				formatter.format_token(&as_token)?,
+				space_token(),
+				formatter.format_node(alias)?
+			],
+			_ => empty_element(),
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&l_brack)?,
+			formatter.format_token(&self.name_token()?)?,
+			space_token(),
+			formatter.format_token(&self.in_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?,
+			alias,
+			formatter.format_token(&r_brack)?
+		])
+	}
+}
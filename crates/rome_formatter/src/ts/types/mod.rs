@@ -0,0 +1,17 @@
+mod any_type;
+mod array_type;
+mod conditional_type;
+mod function_type;
+mod intersection_type;
+mod mapped_type;
+mod object_type;
+mod predicate;
+mod reference_type;
+mod template_type;
+mod tuple_type;
+mod type_annotation;
+mod type_members;
+mod type_params;
+mod union_type;
+
+pub(crate) use type_members::format_type_members;
@@ -0,0 +1,28 @@
+use crate::ts::types::format_type_members;
+use crate::{
+	format_elements, group_elements, indent, join_elements, soft_line_break_or_space,
+	FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::TsObjectType;
+
+impl ToFormatElement for TsObjectType {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let l_curly = formatter.format_token(&self.l_curly_token()?)?;
+		let r_curly = formatter.format_token(&self.r_curly_token()?)?;
+		let members = format_type_members(self.members(), formatter)?;
+
+		if members.is_empty() {
+			return Ok(format_elements![l_curly, r_curly]);
+		}
+
+		Ok(group_elements(format_elements![
+			l_curly,
+			indent(format_elements![
+				soft_line_break_or_space(),
+				join_elements(soft_line_break_or_space(), members)
+			]),
+			soft_line_break_or_space(),
+			r_curly
+		]))
+	}
+}
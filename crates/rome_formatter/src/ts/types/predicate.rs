@@ -0,0 +1,39 @@
+use crate::{
+	empty_element, format_elements, space_token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::{TsPredicate, TsThisOrMore};
+
+impl ToFormatElement for TsPredicate {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let asserts = match self.asserts_keyword() {
+			Some(asserts) => format_elements![formatter.format_token(&asserts)?, space_token()],
+			None => empty_element(),
+		};
+
+		let predicate_type = match (self.is_keyword(), self.predicate_type()) {
+			(Some(is), Some(predicate_type)) => format_elements![
+				space_token(),
+				formatter.format_token(&is)?,
+				space_token(),
+				formatter.format_node(predicate_type)?
+			],
+			_ => empty_element(),
+		};
+
+		Ok(format_elements![
+			asserts,
+			formatter.format_node(self.lhs()?)?,
+			predicate_type
+		])
+	}
+}
+
+impl ToFormatElement for TsThisOrMore {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsThisOrMore::TsThis(this) => this.to_format_element(formatter),
+			TsThisOrMore::TsTypeName(name) => name.to_format_element(formatter),
+		}
+	}
+}
@@ -0,0 +1,90 @@
+use crate::{
+	empty_element, format_elements, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	TsEntityName, TsExprWithTypeArgs, TsImport, TsQualifiedPath, TsTypeName, TsTypeRef,
+};
+
+impl ToFormatElement for TsTypeRef {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_args = match self.type_args() {
+			Ok(type_args) => formatter.format_node(type_args)?,
+			Err(_) => empty_element(),
+		};
+
+		Ok(format_elements![
+			formatter.format_node(self.name()?)?,
+			type_args
+		])
+	}
+}
+
+impl ToFormatElement for TsEntityName {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsEntityName::TsTypeName(name) => name.to_format_element(formatter),
+			TsEntityName::TsQualifiedPath(path) => path.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsTypeName {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		formatter.format_token(&self.ident_token()?)
+	}
+}
+
+impl ToFormatElement for TsQualifiedPath {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_node(self.lhs()?)?,
+			formatter.format_token(&self.dot_token()?)?,
+			formatter.format_node(self.member()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsExprWithTypeArgs {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let type_args = match self.type_args() {
+			Some(type_args) => formatter.format_node(type_args)?,
+			None => empty_element(),
+		};
+
+		Ok(format_elements![
+			formatter.format_node(self.item()?)?,
+			type_args
+		])
+	}
+}
+
+impl ToFormatElement for TsImport {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let arg = match self.arg() {
+			Some(arg) => formatter.format_string_literal(&arg),
+			None => empty_element(),
+		};
+
+		let qualifier = match (self.dot_token(), self.qualifier_name()) {
+			(Some(dot), Some(qualifier)) => format_elements![
+				formatter.format_token(&dot)?,
+				formatter.format_node(qualifier)?
+			],
+			_ => empty_element(),
+		};
+
+		let type_args = match self.type_arguments() {
+			Some(type_args) => formatter.format_node(type_args)?,
+			None => empty_element(),
+		};
+
+		Ok(format_elements![
+			formatter.format_token(&self.import_token()?)?,
+			formatter.format_token(&self.l_paren_token()?)?,
+			arg,
+			formatter.format_token(&self.r_paren_token()?)?,
+			qualifier,
+			type_args
+		])
+	}
+}
@@ -0,0 +1,34 @@
+use crate::ts::expressions::format_flat;
+use crate::{format_elements, FormatElement, FormatResult, Formatter, ToFormatElement};
+use rslint_parser::ast::{TsTemplate, TsTemplateElement, TsTemplatePart};
+
+impl ToFormatElement for TsTemplate {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let parts = self
+			.parts()
+			.map(|part| match part {
+				TsTemplatePart::Chunk(chunk) => formatter.format_token(&chunk),
+				TsTemplatePart::Element(element) => formatter.format_node(element),
+			})
+			.collect::<FormatResult<Vec<_>>>()?;
+
+		Ok(format_elements![
+			formatter.format_token(&self.l_tick_token()?)?,
+			concat_elements(parts),
+			formatter.format_token(&self.r_tick_token()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsTemplateElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		// Like the expressions of a template literal, the type stays on the line of the template
+		let ty = format_flat(formatter.format_node(self.ty()?)?);
+
+		Ok(format_elements![
+			formatter.format_token(&self.dollar_curly_token()?)?,
+			ty,
+			formatter.format_token(&self.r_curly_token()?)?
+		])
+	}
+}
@@ -0,0 +1,48 @@
+use crate::{
+	empty_element, format_elements, group_elements, join_elements, soft_indent,
+	soft_line_break_or_space, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+	TrailingSeparator,
+};
+use rslint_parser::ast::{TsTuple, TsTupleElement};
+
+impl ToFormatElement for TsTuple {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let elements = formatter.format_separated(self.element_list(), TrailingSeparator::Es5)?;
+
+		Ok(group_elements(format_elements![
+			formatter.format_token(&self.l_brack_token()?)?,
+			soft_indent(join_elements(soft_line_break_or_space(), elements)),
+			formatter.format_token(&self.r_brack_token()?)?
+		]))
+	}
+}
+
+impl ToFormatElement for TsTupleElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let dotdotdot = match self.dotdotdot_token() {
+			Some(dotdotdot) => formatter.format_token(&dotdotdot)?,
+			None => empty_element(),
+		};
+
+		let question_mark = match self.question_mark_token() {
+			Ok(question_mark) => formatter.format_token(&question_mark)?,
+			Err(_) => empty_element(),
+		};
+
+		let ty = formatter.format_node(self.ty()?)?;
+
+		match self.name() {
+			// `[a?: string]`
+			Some(name) => Ok(format_elements![
+				dotdotdot,
+				formatter.format_node(name)?,
+				question_mark,
+				formatter.format_token(&self.colon_token()?)?,
+				space_token(),
+				ty
+			]),
+			// `[string?]`
+			None => Ok(format_elements![dotdotdot, ty, question_mark]),
+		}
+	}
+}
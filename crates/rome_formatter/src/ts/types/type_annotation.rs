@@ -0,0 +1,14 @@
+use crate::{
+	format_elements, space_token, FormatElement, FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::TsTypeAnnotation;
+
+impl ToFormatElement for TsTypeAnnotation {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.colon_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
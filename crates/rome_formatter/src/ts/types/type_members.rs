@@ -0,0 +1,205 @@
+use crate::{
+	empty_element, format_elements, if_group_breaks, space_token, token, FormatElement,
+	FormatResult, Formatter, ToFormatElement,
+};
+use rslint_parser::ast::{
+	AstNodeList, TsCallSignatureDecl, TsConstructSignatureDecl, TsIndexSignature,
+	TsMethodSignature, TsPropertySignature, TsSignatureKey, TsType, TsTypeElement, TsTypeParams,
+};
+use rslint_parser::SyntaxToken;
+
+/// Formats the members of an interface or an object type, each followed by a `;`.
+///
+/// The `;` after the last member is only printed if the enclosing group breaks.
+pub(crate) fn format_type_members(
+	members: AstNodeList<TsTypeElement>,
+	formatter: &Formatter,
+) -> FormatResult<Vec<FormatElement>> {
+	let last_index = members.len().saturating_sub(1);
+
+	members
+		.iter()
+		.enumerate()
+		.map(|(index, member)| {
+			let separator = if index == last_index {
+				if_group_breaks(token(";"))
+			} else {
+				token(";")
+			};
+
+			// The `,` or `;` of the source is part of the member
+			let separator = match member.separator_token() {
+				Some(separator_token) => formatter.format_replaced(&separator_token, separator),
+				None => separator,
+			};
+
+			Ok(format_elements![formatter.format_node(member)?, separator])
+		})
+		.collect()
+}
+
+impl ToFormatElement for TsTypeElement {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsTypeElement::TsCallSignatureDecl(member) => member.to_format_element(formatter),
+			TsTypeElement::TsConstructSignatureDecl(member) => member.to_format_element(formatter),
+			TsTypeElement::TsPropertySignature(member) => member.to_format_element(formatter),
+			TsTypeElement::TsMethodSignature(member) => member.to_format_element(formatter),
+			TsTypeElement::TsIndexSignature(member) => member.to_format_element(formatter),
+		}
+	}
+}
+
+impl ToFormatElement for TsSignatureKey {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		match self {
+			TsSignatureKey::JsReferenceIdentifierMember(name) => name.to_format_element(formatter),
+			TsSignatureKey::JsAnyExpression(expression) => expression.to_format_element(formatter),
+		}
+	}
+}
+
+/// Formats the key of a property or a method signature, with its brackets if it's computed
+fn format_signature_key(
+	l_brack: Option<SyntaxToken>,
+	key: TsSignatureKey,
+	r_brack: Option<SyntaxToken>,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let key = formatter.format_node(key)?;
+
+	match (l_brack, r_brack) {
+		(Some(l_brack), Some(r_brack)) => Ok(format_elements![
+			formatter.format_token(&l_brack)?,
+			key,
+			formatter.format_token(&r_brack)?
+		]),
+		_ => Ok(key),
+	}
+}
+
+/// Formats the part of a signature after its name: `<T>(a: T): T`
+fn format_signature(
+	type_params: Option<TsTypeParams>,
+	parameters: FormatElement,
+	colon: Option<SyntaxToken>,
+	return_type: Option<TsType>,
+	formatter: &Formatter,
+) -> FormatResult<FormatElement> {
+	let type_params = match type_params {
+		Some(type_params) => formatter.format_node(type_params)?,
+		None => empty_element(),
+	};
+
+	let return_type = match (colon, return_type) {
+		(Some(colon), Some(return_type)) => format_elements![
+			formatter.format_token(&colon)?,
+			space_token(),
+			formatter.format_node(return_type)?
+		],
+		_ => empty_element(),
+	};
+
+	Ok(format_elements![type_params, parameters, return_type])
+}
+
+impl ToFormatElement for TsPropertySignature {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let readonly = match self.readonly_token() {
+			Some(readonly) => format_elements![formatter.format_token(&readonly)?, space_token()],
+			None => empty_element(),
+		};
+
+		let question_mark = match self.question_mark_token() {
+			Ok(question_mark) => formatter.format_token(&question_mark)?,
+			Err(_) => empty_element(),
+		};
+
+		Ok(format_elements![
+			readonly,
+			format_signature_key(
+				self.l_brack_token(),
+				self.name()?,
+				self.r_brack_token(),
+				formatter
+			)?,
+			question_mark,
+			formatter.format_token(&self.colon_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
+
+impl ToFormatElement for TsMethodSignature {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let question_mark = match self.question_mark_token() {
+			Some(question_mark) => formatter.format_token(&question_mark)?,
+			None => empty_element(),
+		};
+
+		Ok(format_elements![
+			format_signature_key(
+				self.l_brack_token(),
+				self.name()?,
+				self.r_brack_token(),
+				formatter
+			)?,
+			question_mark,
+			format_signature(
+				self.type_params().ok(),
+				formatter.format_node(self.parameters()?)?,
+				self.colon_token().ok(),
+				self.return_type().ok(),
+				formatter
+			)?
+		])
+	}
+}
+
+impl ToFormatElement for TsCallSignatureDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		format_signature(
+			self.type_params().ok(),
+			formatter.format_node(self.parameters()?)?,
+			self.colon_token().ok(),
+			self.return_type().ok(),
+			formatter,
+		)
+	}
+}
+
+impl ToFormatElement for TsConstructSignatureDecl {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		Ok(format_elements![
+			formatter.format_token(&self.new_token()?)?,
+			space_token(),
+			format_signature(
+				self.type_params().ok(),
+				formatter.format_node(self.parameters()?)?,
+				self.colon_token(),
+				self.return_type().ok(),
+				formatter
+			)?
+		])
+	}
+}
+
+impl ToFormatElement for TsIndexSignature {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let readonly = match self.readonly_token() {
+			Some(readonly) => format_elements![formatter.format_token(&readonly)?, space_token()],
+			None => empty_element(),
+		};
+
+		Ok(format_elements![
+			readonly,
+			formatter.format_token(&self.l_brack_token()?)?,
+			formatter.format_node(self.pat()?)?,
+			formatter.format_token(&self.r_brack_token()?)?,
+			formatter.format_token(&self.colon_token()?)?,
+			space_token(),
+			formatter.format_node(self.ty()?)?
+		])
+	}
+}
@@ -0,0 +1,40 @@
+use crate::{
+	format_elements, group_elements, if_group_breaks, indent, soft_line_break,
+	soft_line_break_or_space, space_token, token, FormatElement, FormatResult, Formatter,
+	ToFormatElement,
+};
+use rslint_parser::ast::TsUnion;
+
+impl ToFormatElement for TsUnion {
+	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+		let mut pipes = self.pipe_tokens().into_iter();
+		let mut types = Vec::new();
+
+		for (index, ty) in formatter.format_nodes(self.types())?.enumerate() {
+			if index == 0 {
+				types.push(ty);
+			} else {
+				types.push(format_elements![
+					soft_line_break_or_space(),
+					formatter.format_or_insert_token(pipes.next(), "|"),
+					space_token(),
+					ty
+				]);
+			}
+		}
+
+		// A union that doesn't fit on a line starts on a new line with a leading `|`,
+		// putting one member on each line
+		let leading_pipe = if_group_breaks(format_elements![token("|"), space_token()]);
+		let leading_pipe = match self.leading_pipe_token() {
+			Some(pipe) => formatter.format_replaced(&pipe, leading_pipe),
+			None => leading_pipe,
+		};
+
+		Ok(group_elements(indent(format_elements![
+			soft_line_break(),
+			leading_pipe,
+			concat_elements(types)
+		])))
+	}
+}
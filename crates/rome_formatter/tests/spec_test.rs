@@ -1,5 +1,5 @@
 use rome_core::create_app;
-use rome_formatter::{format_file, FormatOptions};
+use rome_formatter::{format_file, FormatOptions, Semicolons, TrailingComma};
 use std::fs;
 use std::path::Path;
 
@@ -14,6 +14,10 @@ use std::path::Path;
 ///
 /// * `json/null` -> input: `tests/specs/json/null.json`, expected output: `tests/specs/json/null.expected.json`
 /// * `null` -> input: `tests/specs/null.json`, expected output: `tests/specs/null.expected.json`
+///
+/// The code of the specs inside an `invalid` directory is formatted even though it contains syntax errors,
+/// the specs inside a `no_semicolons` directory are formatted with [Semicolons::AsNeeded], and the ones
+/// inside a `trailing_comma_none` or `trailing_comma_all` directory with the matching [TrailingComma].
 pub fn run(spec_input_file: &str, expected_file: &str) {
 	let app = create_app();
 	let file_path = &spec_input_file;
@@ -32,7 +36,31 @@ pub fn run(spec_input_file: &str, expected_file: &str) {
 		expected_file.display(),
 	);
 
-	let result = format_file(file_path, FormatOptions::default(), &app);
+	let in_directory = |name: &str| {
+		spec_input_file
+			.components()
+			.any(|component| component.as_os_str() == name)
+	};
+
+	// The specs inside an `invalid` directory test the formatting of code with syntax errors
+	let options = FormatOptions {
+		format_with_errors: in_directory("invalid"),
+		semicolons: if in_directory("no_semicolons") {
+			Semicolons::AsNeeded
+		} else {
+			Semicolons::Always
+		},
+		trailing_comma: if in_directory("trailing_comma_none") {
+			TrailingComma::None
+		} else if in_directory("trailing_comma_all") {
+			TrailingComma::All
+		} else {
+			TrailingComma::Es5
+		},
+		..FormatOptions::default()
+	};
+
+	let result = format_file(file_path, options, &app);
 	let expected_output = fs::read_to_string(expected_file).unwrap();
 
 	assert_eq!(&expected_output, result.code());
@@ -9,5 +9,12 @@ mod formatter {
 	mod js {
 		use crate::spec_test;
 		tests_macros::gen_tests! {"tests/specs/js/**/**.js", spec_test::run}
+		// The `.mjs` files are parsed as ES modules
+		tests_macros::gen_tests! {"tests/specs/js/**/**.mjs", spec_test::run}
+	}
+
+	mod ts {
+		use crate::spec_test;
+		tests_macros::gen_tests! {"tests/specs/ts/**/**.ts", spec_test::run}
 	}
 }
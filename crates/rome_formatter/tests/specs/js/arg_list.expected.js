@@ -9,3 +9,6 @@ function foo(
 ) {
 	return "nothing";
 }
+foo(a, ...b);
+foo(...args, () => {});
+new Foo(a, ...b);
@@ -1,3 +1,6 @@
 function foo(someotherlongvariableshould, someotherlongvariableshould, someotherlongvariableshould, someotherlongvariableshould, someotherlongvariableshould, someotherlongvariableshould, someotherlongvariableshould) {
 	return "nothing";
 }
+foo(a, ...b);
+foo(...args, () => {});
+new Foo(a, ...b);
@@ -0,0 +1,15 @@
+let a = [a, ,];
+let b = [, ,];
+let c = [,];
+let d = [a, , b];
+let e = [
+	someLongElementName,
+	someOtherLongElementName,
+	yetAnotherLongElementName,
+	,
+];
+let f = [
+	someLongElementName,
+	someOtherLongElementName,
+	yetAnotherLongElementName,
+];
@@ -26,16 +26,16 @@ let y = [[1, 0], [1, 0]];
 let z = [{}];
 let a1 = [{}, {}];
 let b1 = [{}, {}, {}];
-let c1 = [{}, {a}];
-let d1 = [{}, {a, b}];
-let e1 = [{}, {a, b, c}];
-let f1 = [{a}];
-let g1 = [{a}, {a}];
-let h1 = [{a}, {a}, {a}];
-let i1 = [{a}, {a, b}];
-let j1 = [{a}, {a, b, c}];
-let k1 = [{a, b}];
-let l1 = [{a, b}, {a}];
-let m1 = [{a, b}, {a}, {a}];
-let n1 = [{a, b}, {a, b}];
-let o1 = [{a, b}, {a, b, c}];
+let c1 = [{}, { a }];
+let d1 = [{}, { a, b }];
+let e1 = [{}, { a, b, c }];
+let f1 = [{ a }];
+let g1 = [{ a }, { a }];
+let h1 = [{ a }, { a }, { a }];
+let i1 = [{ a }, { a, b }];
+let j1 = [{ a }, { a, b, c }];
+let k1 = [{ a, b }];
+let l1 = [{ a, b }, { a }];
+let m1 = [{ a, b }, { a }, { a }];
+let n1 = [{ a, b }, { a, b }];
+let o1 = [{ a, b }, { a, b, c }];
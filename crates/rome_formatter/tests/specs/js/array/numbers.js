@@ -0,0 +1,9 @@
+const matrix = [
+  1, 0, 0,
+  0, 1, 0,
+  0, 0, 1,
+];
+
+const primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+
+const signed = [-1.5, +2, 3e10, 0x1f, -0.25, 100000, 200000, 300000, 400000, 500000, 600000, 700000];
@@ -1,2 +1,2 @@
-let a = [];
+let a = [,];
 let c = [2, 2, 1, 3];
@@ -0,0 +1,12 @@
+a + b * c - d;
+a*b/c;
+veryLongVariableName + anotherVeryLongVariableName + yetAnotherVeryLongVariableName + last;
+const isValid = someCondition && anotherCondition || yetAnotherCondition && theLastConditionOfAll;
+if (someVeryLongConditionName && anotherVeryLongConditionName || aThirdVeryLongConditionName) {}
+const options = userOptions || { tabWidth: 2 };
+function f() {
+	return firstOperandOfTheSum + secondOperandOfTheSum + thirdOperandOfTheSum + fourth;
+}
+x = 1;
+x += y ** 2;
+someVariable = firstValueOfTheExpression * secondValueOfTheExpression + thirdValue;
@@ -0,0 +1,44 @@
+let a = 1;
+let b = 2;
+
+let c = 3;
+// comment
+
+let d = 4;
+
+function f() {
+	a();
+
+	b();
+}
+
+class A {
+	a = 1;
+
+	b() {}
+	c() {}
+}
+
+let o = {
+	a: 1,
+
+	b: 2,
+};
+
+switch (a) {
+	case 1:
+		break;
+
+	case 2:
+		break;
+}
+
+function g() {
+	// first
+
+	// second
+
+	x();
+
+	// end
+}
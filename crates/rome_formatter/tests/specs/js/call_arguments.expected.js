@@ -0,0 +1,20 @@
+foo(a, () => {
+	body();
+});
+
+useEffect(
+	() => {
+		body();
+	},
+	[dependency]
+);
+
+someVeryLongFunctionName(
+	someArgument,
+	anotherArgumentThatIsLongerThanThat,
+	() => {
+		body();
+	}
+);
+
+foo(a, b, [1, 2, 3]);
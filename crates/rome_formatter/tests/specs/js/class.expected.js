@@ -2,15 +2,22 @@ class Foo extends Boar {
 	constructor() {
 		super();
 	}
+
 	get g() {}
+
 	set gg(a) {}
+
 	lorem() {
 		return "ipsum";
 	}
+
 	static foo;
+
 	static bar() {
 		return "bar";
 	}
+
 	new_prop = 5;
+
 	double_semicolon = [5, 3, 4];
 }
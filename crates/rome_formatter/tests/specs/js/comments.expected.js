@@ -0,0 +1,27 @@
+#!/usr/bin/env node
+// License header
+// spanning two lines
+
+/**
+ * Documentation comment
+ *   @param a the first parameter
+ */
+function f(a /* first */) { // after the brace
+	// dangling in an empty body
+}
+
+function g() {
+	let x = 1; // trailing
+	/* leading */ foo();
+	/* own line */
+	return x;
+	// dangling after the last statement
+}
+
+if (a) { /* block */
+	b();
+}
+// end of file
+function noParams(/* no params */) {}
+foo(/* a */ b);
+const first = [/* first */ 1];
@@ -0,0 +1,17 @@
+const a = b ? c : d;
+const message = isError
+	? "An error occurred while processing the request"
+	: "The request succeeded";
+const animal = isBird
+	? "bird"
+	: isCat
+	? "cat"
+	: isDog
+	? "dog"
+	: isFish
+	? "fish"
+	: "unknown animal";
+const result =
+	firstCondition && secondCondition
+		? someValueOfTheExpression
+		: anotherValueOfIt;
@@ -1,6 +1,7 @@
 while (true) {
 	continue;
 }
+
 tour: while (true) {
 	continue tour;
 }
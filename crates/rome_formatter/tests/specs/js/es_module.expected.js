@@ -0,0 +1,2 @@
+import { a, b } from "x";
+export const c = a + b;
@@ -1,4 +1,5 @@
 for (a in b) {}
+
 for (
 	aVeryLongVariableNameToEnforceLineBreaksaVeryLongVariableNameToEnforceLineBreaks
 	in
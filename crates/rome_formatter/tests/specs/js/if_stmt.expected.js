@@ -3,10 +3,12 @@ if (true) {
 } else {
 	let x = 10;
 }
+
 if (
 	aVeryLongVeriableNameSoThatTheConditionBreaksAcrossMultipleLinesAndIDontKnow
 ) {
 } else {
 }
+
 if (true) {
 }
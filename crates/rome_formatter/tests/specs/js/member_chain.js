@@ -0,0 +1,8 @@
+promise.then(result => console.log(result)).catch(error => handleErrorProperly(error)).finally(cleanup);
+promise.then(() => { load(); }).catch(() => { log(); }).finally(() => { done(); });
+this.server.listen(port);
+z.string().optional().nullable().describe("some long description of the thing we validate");
+wrapper.find('SomeSelector').prop('children')(defaultValue).props.onChange(newValue);
+items[0].value.toString();
+a?.b?.[c]?.(d);
+this.#private.call(this);
@@ -0,0 +1,25 @@
+import "side-effect";
+import a from "a";
+import b, * as c from "c";
+import d, { e, f as g } from "h";
+import {
+	aVeryLongSpecifierName,
+	anotherVeryLongSpecifierName,
+	yetAnotherSpecifier,
+} from "module";
+import {} from "empty";
+
+export * from "y";
+export * as z from "z";
+export { a, b as bb };
+export { x } from "x";
+export const q = 1;
+export function fn() {}
+export class K {}
+export default class L {}
+export default 1;
+export {
+	aVeryLongSpecifierName,
+	anotherVeryLongSpecifierName,
+	yetAnotherSpecifier,
+} from "module";
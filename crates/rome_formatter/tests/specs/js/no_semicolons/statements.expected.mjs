@@ -0,0 +1,24 @@
+let a = 1
+const b = a
+a
+// the parentheses are kept
+;(a || b).c()
+;[1, 2].forEach(f)
+;`a`.length
+;(a) => a
+;({} = a)
+x = [1]
+a()
+do {} while (a)
+function f() {
+	return a
+}
+import c from "c"
+export { a }
+throw a
+if (a) b(); else c()
+do x(); while (y)
+if (a) b(); else c()
+if (a) if (b) c(); else d()
+do x(); while (y)
+z()
@@ -1 +1,7 @@
 let a = {};
+let methods = {
+	m() {
+		return a;
+	},
+	async *n(b) {},
+};
@@ -1,2 +1,3 @@
 let a = {
 };
+let methods = { m() { return a }, async *n(b) {} };
@@ -0,0 +1,35 @@
+(a + b) * c;
+a + (b * c);
+(a - b) - c;
+a - (b - c);
+(a ?? b) || c;
+a && b || c;
+(a || b) && c;
+a % b + c;
+(a * b) % c;
+(a + b) << c;
+((a));
+f((a, b));
+(a, b);
+x = (a, b);
+({}).toString();
+({ a } = b);
+(function () {})();
+(function () {}).call(this);
+(() => {})();
+x = () => ({});
+(a ? b : c) + d;
+(a ? b : c) ? d : e;
+a ? (b ? c : d) : e;
+x = (a = b);
+-(-a);
+(-a) ** b;
+(a + b);
+const c = (a, b);
+(a?.b).c;
+(a?.b)();
+(a?.b)?.c;
+a?.b.c;
+new (a().b)();
+new (a.b().c)();
+new (a.b)();
@@ -1,7 +1,9 @@
 function foo() {
 	let [ref, setRef] = useState();
+
 	useEffect(() => {
 		setRef();
 	});
+
 	return ref;
 }
@@ -0,0 +1,7 @@
+let a = "double";
+let b = "single";
+let c = "it's";
+let d = 'say "hi"';
+let e = 'say "hi" it\'s';
+let f = "a\nb\\";
+let o = { "key": 1, "it's": 2, 'a"b': 3 };
@@ -0,0 +1,32 @@
+const a = 1;
+
+// rome-ignore format: the matrix is aligned by hand
+const matrix = [
+    1, 0, 0,
+    0, 1, 0,
+    0, 0, 1
+];
+
+class A {
+	// prettier-ignore
+	method( a,b ) { return a+b }
+
+	other( a,b ) { return a+b }
+}
+
+const o = {
+	// rome-ignore format: kept
+	key:   'value',
+	other:   'value'
+};
+
+function f() {
+	/* rome-ignore format */
+	if (a)   b ( ) ;
+}
+
+foo(
+	// rome-ignore format: kept
+	promise .then( a ) .catch( b ),
+	other( a ) . then( b )
+);
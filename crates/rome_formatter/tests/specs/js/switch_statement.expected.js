@@ -1,6 +1,7 @@
 switch (key) {
 	case value:
 		break;
+
 	default:
 		break;
 }
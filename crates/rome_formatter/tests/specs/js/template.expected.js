@@ -0,0 +1,10 @@
+const a = `simple`;
+const b = `a ${b} c ${d + e}`;
+const c = tag`hello ${world}`;
+const d = `
+  multiline
+    ${value}   chunk
+`;
+const e = `${veryLongFunctionName(firstArgument.withSomeMember)} and ${anotherVeryLongName(third.fourth)}`;
+const f = String.raw`\n${a}`;
+const g = `${`nested ${x}`}`;
@@ -1,2 +1,3 @@
 throw "Something";
+
 throw false;
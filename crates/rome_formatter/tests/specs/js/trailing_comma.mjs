@@ -0,0 +1,13 @@
+let array = [someLongElementName, someOtherLongElementName, yetAnotherLongElementName,];
+let object = { someLongPropertyName: 1, someOtherLongPropertyName: 2, yetAnotherLongPropertyName: 3, };
+let short = [a, b,];
+foo(a,b);
+foo(a, () => {
+	return a;
+});
+function bar(someLongParameterName, someOtherLongParameterName, ...yetAnotherLongParameterName) {}
+function baz(someLongParameterName, someOtherLongParameterName, yetAnotherLongParameterName,) {}
+someFunctionName(someLongArgumentName, someOtherLongArgumentName, yetAnotherLongArgumentName);
+import { someLongImportName, someOtherLongImportName, yetAnotherLongImportName, andMore } from "module";
+const [someLongElementName, someOtherLongElementName, yetAnotherLongElementName, more] = x;
+const [a, b,] = x;
@@ -0,0 +1,15 @@
+const a = 1 // note
+
+foo(a, // first
+ b)
+
+let x = [1, // one
+2]
+
+foo(a) // call
+
+const t = `${a // in template
+}`
+
+function f() { // body
+ a(); }
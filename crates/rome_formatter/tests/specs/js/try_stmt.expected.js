@@ -3,16 +3,19 @@ try {
 } catch {
 	var foo = 4;
 }
+
 try {
 	var foo = 4;
 } catch (e) {
 	var foo = 4;
 }
+
 try {
 	var foo = 4;
 } finally {
 	var foo = 4;
 }
+
 try {
 	var foo = 4;
 } catch {
@@ -1,6 +1,7 @@
 while (true) {
 	var foo = 4;
 }
+
 while (true) {
 	var foo = 4;
 }
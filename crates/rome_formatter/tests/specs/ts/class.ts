@@ -0,0 +1,14 @@
+class P<T> extends Q<T> implements R, S<T> {
+  private readonly x: string = "a";
+  static y?: number;
+  z!: boolean;
+  [key: string]: any;
+  constructor(a: string);
+  constructor() {}
+  foo<U>(a: U): U { return a }
+  protected get v(): number { return 1 }
+}
+abstract class Z {
+  abstract bar(): void;
+  public static async *gen() {}
+}
@@ -0,0 +1,26 @@
+declare const VERSION: string;
+declare let x: number | undefined;
+export declare function create<T>(options?: Partial<T>): T;
+export declare function create(): void;
+export declare class Client {
+	constructor(url: string);
+	get url(): string;
+	request<R = unknown>(path: string, ...args: any[]): Promise<R>;
+	static create(): Client;
+	private _internal;
+}
+export interface Options {
+	timeout?: number;
+	retries?: number;
+	onError?: (err: Error) => void;
+}
+declare module "*.css" {
+	const classes: { [key: string]: string };
+	export default classes;
+}
+declare namespace NodeJS {
+	interface Global {
+		fetch: typeof fetch;
+	}
+}
+export default Client;
@@ -0,0 +1,4 @@
+enum Color { Red, Green = "g", Blue = 4 }
+const enum Flags { A = 1 << 0, B = 1 << 1 }
+enum Empty {}
+export enum Exported { "quoted" = 1 }
@@ -0,0 +1,14 @@
+interface Foo<T extends object = {}> extends Bar<T>, Baz {
+	readonly a: string;
+	b?(x: number): void;
+	[key: string]: any;
+	new (x: number): Foo<T>;
+	<U>(u: U): U;
+	[Symbol.iterator](): Iterator<T>;
+}
+interface Empty {}
+let annotated: { a: number; b: Array<string> } = { a: 1, b: [] };
+function isString(x: unknown, y?: number): x is string {}
+function assert(x: unknown): asserts x {}
+const identity = <T>(value: T): T => value;
+call<string>(a);
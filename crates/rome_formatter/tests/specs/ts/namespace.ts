@@ -0,0 +1,6 @@
+namespace a.b.c { const x = 1 }
+module m { export type T = string }
+declare global { interface Window { a: string } }
+declare module "foo" {
+  export function f(a: string): number;
+}
@@ -0,0 +1,17 @@
+type A = string|number;
+type LongUnion = "aaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbb" | "cccccccccccccccccc" | "dddddddddddddddddd";
+type Leading =
+  | "a"
+  | "b";
+type Inter = Aaaaaaaaaaaaaaaaaaaaaaaaaaa & Bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb & Cccccccccccccccccccccccc;
+type Obj = {a:string,b?:number};
+type Mapped<T> = {readonly [K in keyof T]?: T[K]};
+type Renamed<T> = { -readonly [K in keyof T as `get${K}`]-?: () => T[K] };
+type Tuple = [a: string, b?: number, ...rest: boolean[]];
+type Fn = <T>(a: T, b: string) => void;
+type Ctor = new (a: string) => Foo;
+type Cond<T> = T extends string ? "s" : T extends (infer U)[] ? U : never;
+type Query = typeof import("x").A<B>;
+type Keys = keyof typeof obj;
+type Indexed = Foo["bar"][number];
+type Literal = -1 | true | null | undefined;
@@ -76,8 +76,8 @@ impl<'handler> RomePath<'handler> {
 
 	/// Accepts a file opened in read mode and saves into it
 	pub fn save(&mut self, content: &str) -> Result<(), std::io::Error> {
-		let mut file_to_write = File::create(&self.file).unwrap();
 		// TODO: handle error with diagnostic
+		let mut file_to_write = File::create(&self.file)?;
 		file_to_write.write_all(content.as_bytes())
 	}
 
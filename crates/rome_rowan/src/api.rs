@@ -100,7 +100,7 @@ impl<L: Language> SyntaxTriviaPiece<L> {
 	/// ```
 	pub fn text(&self) -> &str {
 		let txt = self.raw.text();
-		let start = self.offset - self.raw.offset();
+		let start = self.offset - self.raw.text_range().start();
 		let end = start + self.text_len();
 
 		&txt[start.into()..end.into()]
@@ -145,6 +145,10 @@ impl<L: Language> SyntaxTriviaPiece<L> {
 	/// });
 	/// let pieces: Vec<_> = node.first_leading_trivia().unwrap().pieces().collect();
 	/// assert_eq!(TextRange::new(0.into(), 3.into()), pieces[0].text_range());
+	///
+	/// let trailing: Vec<_> = node.first_token().unwrap().trailing_trivia().pieces().collect();
+	/// assert_eq!(TextRange::new(10.into(), 13.into()), trailing[0].text_range());
+	/// assert_eq!(" \t\t", trailing[0].text());
 	/// ```
 	pub fn text_range(&self) -> TextRange {
 		TextRange::at(self.offset, self.text_len())
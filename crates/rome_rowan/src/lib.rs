@@ -31,7 +31,7 @@ pub use text_size::{TextLen, TextRange, TextSize};
 pub use crate::{
 	api::{
 		Language, SyntaxElement, SyntaxElementChildren, SyntaxList, SyntaxNode, SyntaxNodeChildren,
-		SyntaxSlot, SyntaxSlots, SyntaxToken, TriviaPiece,
+		SyntaxSlot, SyntaxSlots, SyntaxToken, SyntaxTriviaPiece, TriviaPiece,
 	},
 	green::SyntaxKind,
 	syntax_text::SyntaxText,
@@ -125,6 +125,11 @@ impl<N: AstNode> AstNodeList<N> {
 		}
 	}
 
+	/// Returns the syntax nodes of this list, including the `ERROR` nodes that [AstNodeList::iter] skips
+	pub fn syntax_nodes(&self) -> impl Iterator<Item = SyntaxNode> {
+		self.inner.iter().filter_map(|slot| slot.into_node())
+	}
+
 	#[inline]
 	pub fn len(&self) -> usize {
 		self.inner.len()
@@ -361,14 +361,50 @@ impl JsArrowFunctionExpression {
 }
 
 impl Template {
+	/// The tag of a tagged template, `tag` in ``tag`foo` ``
+	pub fn tag(&self) -> Option<JsAnyExpression> {
+		support::node(self.syntax())
+	}
+
+	/// The type arguments of a tagged template, `<string>` in ``tag<string>`foo` ``
+	pub fn type_args(&self) -> Option<TsTypeArgs> {
+		support::node(self.syntax())
+	}
+
+	/// The string chunks and the `${expression}` elements of the template, in source order
+	pub fn elements(&self) -> impl Iterator<Item = TemplatePart> {
+		self.syntax()
+			.children()
+			.find(|child| child.kind() == LIST)
+			.into_iter()
+			.flat_map(|list| list.children_with_tokens())
+			.filter_map(|element| match element {
+				NodeOrToken::Token(token) if token.kind() == TEMPLATE_CHUNK => {
+					Some(TemplatePart::Chunk(token))
+				}
+				NodeOrToken::Node(node) => TemplateElement::cast(node).map(TemplatePart::Element),
+				_ => None,
+			})
+	}
+
+	/// The closing backtick, missing if the template is unterminated
+	pub fn r_tick_token(&self) -> SyntaxResult<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|element| element.into_token())
+			.filter(|token| token.kind() == BACKTICK)
+			.nth(1)
+			.ok_or_else(|| SyntaxError::MissingRequiredChild(self.syntax().clone()))
+	}
+
 	/// The string chunks of the template. aka:
 	/// `foo ${bar} foo` breaks down into:
 	/// `QUASIS ELEMENT{EXPR} QUASIS`
 	pub fn quasis(&self) -> impl Iterator<Item = SyntaxToken> {
-		self.syntax()
-			.children_with_tokens()
-			.filter_map(NodeOrToken::into_token)
-			.filter(|t| t.kind() == TEMPLATE_CHUNK)
+		self.elements().filter_map(|part| match part {
+			TemplatePart::Chunk(chunk) => Some(chunk),
+			TemplatePart::Element(_) => None,
+		})
 	}
 
 	pub fn template_range(&self) -> Option<TextRange> {
@@ -384,6 +420,53 @@ impl Template {
 	}
 }
 
+/// A part of a template literal, see [Template::elements]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemplatePart {
+	/// A string chunk, printed as it is
+	Chunk(SyntaxToken),
+	/// An interpolated expression, `${expression}`
+	Element(TemplateElement),
+}
+
+/// An interpolated expression of a template literal: `${expression}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TemplateElement {
+	syntax: SyntaxNode,
+}
+
+impl TemplateElement {
+	pub fn dollar_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, DOLLARCURLY)
+	}
+
+	pub fn expression(&self) -> SyntaxResult<JsAnyExpression> {
+		support::required_node(&self.syntax)
+	}
+
+	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T!['}'])
+	}
+}
+
+impl AstNode for TemplateElement {
+	fn can_cast(kind: SyntaxKind) -> bool {
+		kind == TEMPLATE_ELEMENT
+	}
+
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+
+	fn syntax(&self) -> &SyntaxNode {
+		&self.syntax
+	}
+}
+
 impl CallExpr {
 	pub fn opt_chain_token(&self) -> Option<SyntaxToken> {
 		self.syntax()
@@ -375,6 +375,9 @@ pub struct JsFunctionDeclaration {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsFunctionDeclaration {
+	pub fn declare_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![declare])
+	}
 	pub fn async_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![async]) }
 	pub fn function_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![function])
@@ -387,16 +390,24 @@ impl JsFunctionDeclaration {
 	}
 	pub fn return_type(&self) -> Option<TsTypeAnnotation> { support::node(&self.syntax) }
 	pub fn body(&self) -> SyntaxResult<JsFunctionBody> { support::required_node(&self.syntax) }
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsClassDeclaration {
 	pub(crate) syntax: SyntaxNode,
 }
 impl JsClassDeclaration {
+	pub fn declare_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![declare])
+	}
+	pub fn abstract_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![abstract])
+	}
 	pub fn class_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![class])
 	}
 	pub fn id(&self) -> SyntaxResult<JsIdentifierBinding> { support::required_node(&self.syntax) }
+	pub fn type_parameters(&self) -> Option<TsTypeParams> { support::node(&self.syntax) }
 	pub fn implements_clause(&self) -> Option<TsImplementsClause> { support::node(&self.syntax) }
 	pub fn extends_clause(&self) -> Option<JsExtendsClause> { support::node(&self.syntax) }
 	pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
@@ -507,7 +518,9 @@ impl ImportDecl {
 	pub fn import_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![import])
 	}
-	pub fn imports(&self) -> AstNodeList<ImportClause> { support::node_list(&self.syntax, 0usize) }
+	pub fn imports(&self) -> AstSeparatedList<ImportClause> {
+		support::separated_list(&self.syntax, 0usize)
+	}
 	pub fn type_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![type]) }
 	pub fn from_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![from])
@@ -526,11 +539,7 @@ pub struct ExportNamed {
 	pub(crate) syntax: SyntaxNode,
 }
 impl ExportNamed {
-	pub fn export_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![export])
-	}
 	pub fn type_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![type]) }
-	pub fn from_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![from]) }
 	pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['{'])
 	}
@@ -540,6 +549,11 @@ impl ExportNamed {
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
 	}
+	pub fn from_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![from]) }
+	pub fn source_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T![js_string_literal])
+	}
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ExportDefaultDecl {
@@ -564,6 +578,7 @@ impl ExportDefaultExpr {
 	pub fn type_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![type]) }
 	pub fn default_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![default]) }
 	pub fn expr(&self) -> SyntaxResult<JsAnyExpression> { support::required_node(&self.syntax) }
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ExportWildcard {
@@ -578,13 +593,14 @@ impl ExportWildcard {
 		support::required_token(&self.syntax, T ! [*])
 	}
 	pub fn as_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![as]) }
-	pub fn ident(&self) -> Option<Ident> { support::node(&self.syntax) }
+	pub fn ident(&self) -> Option<Name> { support::node(&self.syntax) }
 	pub fn from_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![from])
 	}
 	pub fn source_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![js_string_literal])
 	}
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ExportDecl {
@@ -604,13 +620,11 @@ pub struct TsImportEqualsDecl {
 	pub(crate) syntax: SyntaxNode,
 }
 impl TsImportEqualsDecl {
+	pub fn export_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![export]) }
 	pub fn import_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![import])
 	}
-	pub fn export_token(&self) -> SyntaxResult<SyntaxToken> {
-		support::required_token(&self.syntax, T![export])
-	}
-	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn ident(&self) -> SyntaxResult<Name> { support::required_node(&self.syntax) }
 	pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T ! [=])
 	}
@@ -645,7 +659,7 @@ impl TsNamespaceExportDecl {
 	pub fn namespace_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![namespace])
 	}
-	pub fn ident(&self) -> Option<Ident> { support::node(&self.syntax) }
+	pub fn ident(&self) -> Option<Name> { support::node(&self.syntax) }
 	pub fn semicolon_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T ! [;]) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -1121,6 +1135,9 @@ pub struct CallExpr {
 impl CallExpr {
 	pub fn type_args(&self) -> Option<TsTypeArgs> { support::node(&self.syntax) }
 	pub fn callee(&self) -> SyntaxResult<JsAnyExpression> { support::required_node(&self.syntax) }
+	pub fn optional_chain_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [?.])
+	}
 	pub fn arguments(&self) -> SyntaxResult<ArgList> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -1213,7 +1230,7 @@ impl ArgList {
 	pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['('])
 	}
-	pub fn args(&self) -> AstSeparatedList<JsAnyExpression> {
+	pub fn args(&self) -> AstSeparatedList<JsAnyCallArgument> {
 		support::separated_list(&self.syntax, 0usize)
 	}
 	pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
@@ -1228,6 +1245,11 @@ impl JsIdentifierBinding {
 	pub fn name_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![ident])
 	}
+	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [?])
+	}
+	pub fn excl_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![!]) }
+	pub fn ty(&self) -> Option<TsTypeAnnotation> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsTypeParams {
@@ -1807,6 +1829,11 @@ impl JsObjectBinding {
 	pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T!['}'])
 	}
+	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [?])
+	}
+	pub fn excl_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![!]) }
+	pub fn ty(&self) -> Option<TsTypeAnnotation> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsArrayBinding {
@@ -1822,6 +1849,11 @@ impl JsArrayBinding {
 	pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
 		support::required_token(&self.syntax, T![']'])
 	}
+	pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+		support::token(&self.syntax, T ! [?])
+	}
+	pub fn excl_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![!]) }
+	pub fn ty(&self) -> Option<TsTypeAnnotation> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsArrayRestBinding {
@@ -1949,7 +1981,7 @@ impl WildcardImport {
 		support::required_token(&self.syntax, T ! [*])
 	}
 	pub fn as_token(&self) -> Option<SyntaxToken> { support::token(&self.syntax, T![as]) }
-	pub fn ident(&self) -> Option<Ident> { support::node(&self.syntax) }
+	pub fn ident(&self) -> Option<JsIdentifierBinding> { support::node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct NamedImports {
@@ -1980,7 +2012,7 @@ pub struct Specifier {
 	pub(crate) syntax: SyntaxNode,
 }
 impl Specifier {
-	pub fn name(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
+	pub fn name(&self) -> SyntaxResult<Name> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct JsReferencePrivateMember {
@@ -2337,6 +2369,16 @@ impl TsInfer {
 	pub fn ident(&self) -> SyntaxResult<Ident> { support::required_node(&self.syntax) }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TsTypeQuery {
+	pub(crate) syntax: SyntaxNode,
+}
+impl TsTypeQuery {
+	pub fn typeof_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(&self.syntax, T![typeof])
+	}
+	pub fn expr(&self) -> SyntaxResult<TsTypeQueryExpr> { support::required_node(&self.syntax) }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TsTupleElement {
 	pub(crate) syntax: SyntaxNode,
 }
@@ -2772,12 +2814,13 @@ pub enum TsType {
 	TsConditionalType(TsConditionalType),
 	TsObjectType(TsObjectType),
 	TsInfer(TsInfer),
+	TsTypeQuery(TsTypeQuery),
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum ImportClause {
 	WildcardImport(WildcardImport),
 	NamedImports(NamedImports),
-	Name(Name),
+	JsIdentifierBinding(JsIdentifierBinding),
 	ImportStringSpecifier(ImportStringSpecifier),
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -2795,6 +2838,7 @@ pub enum JsAnyExportDeclaration {
 	TsNamespaceDecl(TsNamespaceDecl),
 	TsModuleDecl(TsModuleDecl),
 	TsInterfaceDecl(TsInterfaceDecl),
+	ExportNamed(ExportNamed),
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum JsAnyParameter {
@@ -2803,6 +2847,11 @@ pub enum JsAnyParameter {
 	JsRestParameter(JsRestParameter),
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub enum JsAnyCallArgument {
+	JsAnyExpression(JsAnyExpression),
+	JsSpread(JsSpread),
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum TsModuleRef {
 	TsExternalModuleRef(TsExternalModuleRef),
 	TsEntityName(TsEntityName),
@@ -3546,6 +3595,10 @@ impl AstNode for JsFunctionDeclaration {
 impl std::fmt::Debug for JsFunctionDeclaration {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("JsFunctionDeclaration")
+			.field(
+				"declare_token",
+				&support::DebugOptionalNode(self.declare_token()),
+			)
 			.field(
 				"async_token",
 				&support::DebugOptionalNode(self.async_token()),
@@ -3569,6 +3622,10 @@ impl std::fmt::Debug for JsFunctionDeclaration {
 				&support::DebugOptionalNode(self.return_type()),
 			)
 			.field("body", &support::DebugSyntaxResult(self.body()))
+			.field(
+				"semicolon_token",
+				&support::DebugOptionalNode(self.semicolon_token()),
+			)
 			.finish()
 	}
 }
@@ -3586,11 +3643,23 @@ impl AstNode for JsClassDeclaration {
 impl std::fmt::Debug for JsClassDeclaration {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("JsClassDeclaration")
+			.field(
+				"declare_token",
+				&support::DebugOptionalNode(self.declare_token()),
+			)
+			.field(
+				"abstract_token",
+				&support::DebugOptionalNode(self.abstract_token()),
+			)
 			.field(
 				"class_token",
 				&support::DebugSyntaxResult(self.class_token()),
 			)
 			.field("id", &support::DebugSyntaxResult(self.id()))
+			.field(
+				"type_parameters",
+				&support::DebugOptionalNode(self.type_parameters()),
+			)
 			.field(
 				"implements_clause",
 				&support::DebugOptionalNode(self.implements_clause()),
@@ -3845,12 +3914,7 @@ impl AstNode for ExportNamed {
 impl std::fmt::Debug for ExportNamed {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("ExportNamed")
-			.field(
-				"export_token",
-				&support::DebugSyntaxResult(self.export_token()),
-			)
 			.field("type_token", &support::DebugOptionalNode(self.type_token()))
-			.field("from_token", &support::DebugOptionalNode(self.from_token()))
 			.field(
 				"l_curly_token",
 				&support::DebugSyntaxResult(self.l_curly_token()),
@@ -3860,6 +3924,15 @@ impl std::fmt::Debug for ExportNamed {
 				"r_curly_token",
 				&support::DebugSyntaxResult(self.r_curly_token()),
 			)
+			.field("from_token", &support::DebugOptionalNode(self.from_token()))
+			.field(
+				"source_token",
+				&support::DebugOptionalNode(self.source_token()),
+			)
+			.field(
+				"semicolon_token",
+				&support::DebugOptionalNode(self.semicolon_token()),
+			)
 			.finish()
 	}
 }
@@ -3914,6 +3987,10 @@ impl std::fmt::Debug for ExportDefaultExpr {
 				&support::DebugOptionalNode(self.default_token()),
 			)
 			.field("expr", &support::DebugSyntaxResult(self.expr()))
+			.field(
+				"semicolon_token",
+				&support::DebugOptionalNode(self.semicolon_token()),
+			)
 			.finish()
 	}
 }
@@ -3944,6 +4021,10 @@ impl std::fmt::Debug for ExportWildcard {
 				"source_token",
 				&support::DebugSyntaxResult(self.source_token()),
 			)
+			.field(
+				"semicolon_token",
+				&support::DebugOptionalNode(self.semicolon_token()),
+			)
 			.finish()
 	}
 }
@@ -3985,12 +4066,12 @@ impl std::fmt::Debug for TsImportEqualsDecl {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("TsImportEqualsDecl")
 			.field(
-				"import_token",
-				&support::DebugSyntaxResult(self.import_token()),
+				"export_token",
+				&support::DebugOptionalNode(self.export_token()),
 			)
 			.field(
-				"export_token",
-				&support::DebugSyntaxResult(self.export_token()),
+				"import_token",
+				&support::DebugSyntaxResult(self.import_token()),
 			)
 			.field("ident", &support::DebugSyntaxResult(self.ident()))
 			.field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
@@ -4869,6 +4950,10 @@ impl std::fmt::Debug for CallExpr {
 		f.debug_struct("CallExpr")
 			.field("type_args", &support::DebugOptionalNode(self.type_args()))
 			.field("callee", &support::DebugSyntaxResult(self.callee()))
+			.field(
+				"optional_chain_token",
+				&support::DebugOptionalNode(self.optional_chain_token()),
+			)
 			.field("arguments", &support::DebugSyntaxResult(self.arguments()))
 			.finish()
 	}
@@ -5063,6 +5148,12 @@ impl std::fmt::Debug for JsIdentifierBinding {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		f.debug_struct("JsIdentifierBinding")
 			.field("name_token", &support::DebugSyntaxResult(self.name_token()))
+			.field(
+				"question_mark_token",
+				&support::DebugOptionalNode(self.question_mark_token()),
+			)
+			.field("excl_token", &support::DebugOptionalNode(self.excl_token()))
+			.field("ty", &support::DebugOptionalNode(self.ty()))
 			.finish()
 	}
 }
@@ -6138,6 +6229,12 @@ impl std::fmt::Debug for JsObjectBinding {
 				"r_curly_token",
 				&support::DebugSyntaxResult(self.r_curly_token()),
 			)
+			.field(
+				"question_mark_token",
+				&support::DebugOptionalNode(self.question_mark_token()),
+			)
+			.field("excl_token", &support::DebugOptionalNode(self.excl_token()))
+			.field("ty", &support::DebugOptionalNode(self.ty()))
 			.finish()
 	}
 }
@@ -6164,6 +6261,12 @@ impl std::fmt::Debug for JsArrayBinding {
 				"r_brack_token",
 				&support::DebugSyntaxResult(self.r_brack_token()),
 			)
+			.field(
+				"question_mark_token",
+				&support::DebugOptionalNode(self.question_mark_token()),
+			)
+			.field("excl_token", &support::DebugOptionalNode(self.excl_token()))
+			.field("ty", &support::DebugOptionalNode(self.ty()))
 			.finish()
 	}
 }
@@ -7262,6 +7365,28 @@ impl std::fmt::Debug for TsInfer {
 			.finish()
 	}
 }
+impl AstNode for TsTypeQuery {
+	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TYPE_QUERY }
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		if Self::can_cast(syntax.kind()) {
+			Some(Self { syntax })
+		} else {
+			None
+		}
+	}
+	fn syntax(&self) -> &SyntaxNode { &self.syntax }
+}
+impl std::fmt::Debug for TsTypeQuery {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("TsTypeQuery")
+			.field(
+				"typeof_token",
+				&support::DebugSyntaxResult(self.typeof_token()),
+			)
+			.field("expr", &support::DebugSyntaxResult(self.expr()))
+			.finish()
+	}
+}
 impl AstNode for TsTupleElement {
 	fn can_cast(kind: SyntaxKind) -> bool { kind == TS_TUPLE_ELEMENT }
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -9619,6 +9744,9 @@ impl From<TsObjectType> for TsType {
 impl From<TsInfer> for TsType {
 	fn from(node: TsInfer) -> TsType { TsType::TsInfer(node) }
 }
+impl From<TsTypeQuery> for TsType {
+	fn from(node: TsTypeQuery) -> TsType { TsType::TsTypeQuery(node) }
+}
 impl AstNode for TsType {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		matches!(
@@ -9641,6 +9769,7 @@ impl AstNode for TsType {
 				| TS_CONSTRUCTOR_TYPE
 				| TS_CONDITIONAL_TYPE
 				| TS_OBJECT_TYPE | TS_INFER
+				| TS_TYPE_QUERY
 		)
 	}
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -9676,6 +9805,7 @@ impl AstNode for TsType {
 			TS_CONDITIONAL_TYPE => TsType::TsConditionalType(TsConditionalType { syntax }),
 			TS_OBJECT_TYPE => TsType::TsObjectType(TsObjectType { syntax }),
 			TS_INFER => TsType::TsInfer(TsInfer { syntax }),
+			TS_TYPE_QUERY => TsType::TsTypeQuery(TsTypeQuery { syntax }),
 			_ => return None,
 		};
 		Some(res)
@@ -9713,6 +9843,7 @@ impl AstNode for TsType {
 			TsType::TsConditionalType(it) => &it.syntax,
 			TsType::TsObjectType(it) => &it.syntax,
 			TsType::TsInfer(it) => &it.syntax,
+			TsType::TsTypeQuery(it) => &it.syntax,
 		}
 	}
 }
@@ -9750,6 +9881,7 @@ impl std::fmt::Debug for TsType {
 			TsType::TsConditionalType(it) => std::fmt::Debug::fmt(it, f),
 			TsType::TsObjectType(it) => std::fmt::Debug::fmt(it, f),
 			TsType::TsInfer(it) => std::fmt::Debug::fmt(it, f),
+			TsType::TsTypeQuery(it) => std::fmt::Debug::fmt(it, f),
 		}
 	}
 }
@@ -9759,8 +9891,8 @@ impl From<WildcardImport> for ImportClause {
 impl From<NamedImports> for ImportClause {
 	fn from(node: NamedImports) -> ImportClause { ImportClause::NamedImports(node) }
 }
-impl From<Name> for ImportClause {
-	fn from(node: Name) -> ImportClause { ImportClause::Name(node) }
+impl From<JsIdentifierBinding> for ImportClause {
+	fn from(node: JsIdentifierBinding) -> ImportClause { ImportClause::JsIdentifierBinding(node) }
 }
 impl From<ImportStringSpecifier> for ImportClause {
 	fn from(node: ImportStringSpecifier) -> ImportClause {
@@ -9771,14 +9903,16 @@ impl AstNode for ImportClause {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		matches!(
 			kind,
-			WILDCARD_IMPORT | NAMED_IMPORTS | NAME | IMPORT_STRING_SPECIFIER
+			WILDCARD_IMPORT | NAMED_IMPORTS | JS_IDENTIFIER_BINDING | IMPORT_STRING_SPECIFIER
 		)
 	}
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
 		let res = match syntax.kind() {
 			WILDCARD_IMPORT => ImportClause::WildcardImport(WildcardImport { syntax }),
 			NAMED_IMPORTS => ImportClause::NamedImports(NamedImports { syntax }),
-			NAME => ImportClause::Name(Name { syntax }),
+			JS_IDENTIFIER_BINDING => {
+				ImportClause::JsIdentifierBinding(JsIdentifierBinding { syntax })
+			}
 			IMPORT_STRING_SPECIFIER => {
 				ImportClause::ImportStringSpecifier(ImportStringSpecifier { syntax })
 			}
@@ -9790,7 +9924,7 @@ impl AstNode for ImportClause {
 		match self {
 			ImportClause::WildcardImport(it) => &it.syntax,
 			ImportClause::NamedImports(it) => &it.syntax,
-			ImportClause::Name(it) => &it.syntax,
+			ImportClause::JsIdentifierBinding(it) => &it.syntax,
 			ImportClause::ImportStringSpecifier(it) => &it.syntax,
 		}
 	}
@@ -9800,7 +9934,7 @@ impl std::fmt::Debug for ImportClause {
 		match self {
 			ImportClause::WildcardImport(it) => std::fmt::Debug::fmt(it, f),
 			ImportClause::NamedImports(it) => std::fmt::Debug::fmt(it, f),
-			ImportClause::Name(it) => std::fmt::Debug::fmt(it, f),
+			ImportClause::JsIdentifierBinding(it) => std::fmt::Debug::fmt(it, f),
 			ImportClause::ImportStringSpecifier(it) => std::fmt::Debug::fmt(it, f),
 		}
 	}
@@ -9878,6 +10012,9 @@ impl From<TsInterfaceDecl> for JsAnyExportDeclaration {
 		JsAnyExportDeclaration::TsInterfaceDecl(node)
 	}
 }
+impl From<ExportNamed> for JsAnyExportDeclaration {
+	fn from(node: ExportNamed) -> JsAnyExportDeclaration { JsAnyExportDeclaration::ExportNamed(node) }
+}
 impl AstNode for JsAnyExportDeclaration {
 	fn can_cast(kind: SyntaxKind) -> bool {
 		matches!(
@@ -9888,6 +10025,7 @@ impl AstNode for JsAnyExportDeclaration {
 				| TS_ENUM | TS_TYPE_ALIAS_DECL
 				| TS_NAMESPACE_DECL
 				| TS_MODULE_DECL | TS_INTERFACE_DECL
+				| EXPORT_NAMED
 		)
 	}
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -9914,6 +10052,7 @@ impl AstNode for JsAnyExportDeclaration {
 			TS_INTERFACE_DECL => {
 				JsAnyExportDeclaration::TsInterfaceDecl(TsInterfaceDecl { syntax })
 			}
+			EXPORT_NAMED => JsAnyExportDeclaration::ExportNamed(ExportNamed { syntax }),
 			_ => return None,
 		};
 		Some(res)
@@ -9928,6 +10067,7 @@ impl AstNode for JsAnyExportDeclaration {
 			JsAnyExportDeclaration::TsNamespaceDecl(it) => &it.syntax,
 			JsAnyExportDeclaration::TsModuleDecl(it) => &it.syntax,
 			JsAnyExportDeclaration::TsInterfaceDecl(it) => &it.syntax,
+			JsAnyExportDeclaration::ExportNamed(it) => &it.syntax,
 		}
 	}
 }
@@ -9944,6 +10084,7 @@ impl std::fmt::Debug for JsAnyExportDeclaration {
 			JsAnyExportDeclaration::TsNamespaceDecl(it) => std::fmt::Debug::fmt(it, f),
 			JsAnyExportDeclaration::TsModuleDecl(it) => std::fmt::Debug::fmt(it, f),
 			JsAnyExportDeclaration::TsInterfaceDecl(it) => std::fmt::Debug::fmt(it, f),
+			JsAnyExportDeclaration::ExportNamed(it) => std::fmt::Debug::fmt(it, f),
 		}
 	}
 }
@@ -9995,6 +10136,44 @@ impl std::fmt::Debug for JsAnyParameter {
 		}
 	}
 }
+impl From<JsSpread> for JsAnyCallArgument {
+	fn from(node: JsSpread) -> JsAnyCallArgument { JsAnyCallArgument::JsSpread(node) }
+}
+impl AstNode for JsAnyCallArgument {
+	fn can_cast(kind: SyntaxKind) -> bool {
+		match kind {
+			JS_SPREAD => true,
+			k if JsAnyExpression::can_cast(k) => true,
+			_ => false,
+		}
+	}
+	fn cast(syntax: SyntaxNode) -> Option<Self> {
+		let res = match syntax.kind() {
+			JS_SPREAD => JsAnyCallArgument::JsSpread(JsSpread { syntax }),
+			_ => {
+				if let Some(js_any_expression) = JsAnyExpression::cast(syntax) {
+					return Some(JsAnyCallArgument::JsAnyExpression(js_any_expression));
+				}
+				return None;
+			}
+		};
+		Some(res)
+	}
+	fn syntax(&self) -> &SyntaxNode {
+		match self {
+			JsAnyCallArgument::JsSpread(it) => &it.syntax,
+			JsAnyCallArgument::JsAnyExpression(it) => it.syntax(),
+		}
+	}
+}
+impl std::fmt::Debug for JsAnyCallArgument {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			JsAnyCallArgument::JsAnyExpression(it) => std::fmt::Debug::fmt(it, f),
+			JsAnyCallArgument::JsSpread(it) => std::fmt::Debug::fmt(it, f),
+		}
+	}
+}
 impl From<TsExternalModuleRef> for TsModuleRef {
 	fn from(node: TsExternalModuleRef) -> TsModuleRef { TsModuleRef::TsExternalModuleRef(node) }
 }
@@ -10329,6 +10508,11 @@ impl std::fmt::Display for JsAnyParameter {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for JsAnyCallArgument {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsModuleRef {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
@@ -11254,6 +11438,11 @@ impl std::fmt::Display for TsInfer {
 		std::fmt::Display::fmt(self.syntax(), f)
 	}
 }
+impl std::fmt::Display for TsTypeQuery {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.syntax(), f)
+	}
+}
 impl std::fmt::Display for TsTupleElement {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		std::fmt::Display::fmt(self.syntax(), f)
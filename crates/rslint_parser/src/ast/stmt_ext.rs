@@ -48,12 +48,19 @@ impl JsVariableDeclaration {
 	}
 }
 
+impl JsVariableDeclarationStatement {
+	/// The `declare` keyword of an ambient variable, `declare const a: string;`
+	pub fn declare_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T![declare])
+	}
+}
+
 impl Specifier {
 	pub fn as_token(&self) -> Option<SyntaxToken> {
 		self.syntax()
 			.children_with_tokens()
 			.filter_map(|x| x.into_token())
-			.nth(1)
+			.find(|x| x.kind() == T![as])
 	}
 
 	pub fn alias(&self) -> Option<Name> {
@@ -61,12 +68,6 @@ impl Specifier {
 	}
 }
 
-impl WildcardImport {
-	pub fn alias(&self) -> Option<Name> {
-		self.syntax().children().find_map(|x| x.try_to())
-	}
-}
-
 impl JsAnySwitchClause {
 	pub fn into_case(self) -> Option<JsCaseClause> {
 		if let JsAnySwitchClause::JsCaseClause(clause) = self {
@@ -77,6 +78,13 @@ impl JsAnySwitchClause {
 	}
 }
 
+impl JsExtendsClause {
+	/// The type arguments of the super class, `<T>` in `class A extends B<T> {}`
+	pub fn type_args(&self) -> Option<TsTypeArgs> {
+		support::node(self.syntax())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::*;
@@ -4,6 +4,7 @@ use crate::{
 	ast::*,
 	syntax_node::SyntaxNode,
 	SyntaxKind::{self, *},
+	T,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -49,7 +50,7 @@ impl TsMappedTypeParam {
 		self.syntax()
 			.children_with_tokens()
 			.filter_map(|x| x.into_token())
-			.find(|x| x.kind() == IDENT && x.text() == "as")
+			.find(|x| x.kind() == IDENT && x.text_trimmed() == "as")
 	}
 
 	pub fn alias(&self) -> Option<TsType> {
@@ -60,32 +61,466 @@ impl TsMappedTypeParam {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum TsModuleRef {
-	TsExternalModuleRef(TsExternalModuleRef),
-	TsEntityName(TsEntityName),
+/// Returns the identifier child token of `syntax` with the text `keyword`.
+///
+/// TypeScript's contextual keywords, for example `type` or `interface`, are identifiers
+/// unless the parser remaps them.
+fn contextual_keyword(syntax: &SyntaxNode, keyword: &str) -> Option<SyntaxToken> {
+	syntax
+		.children_with_tokens()
+		.filter_map(|x| x.into_token())
+		.find(|x| x.kind() == IDENT && x.text_trimmed() == keyword)
+}
+
+fn missing(syntax: &SyntaxNode) -> SyntaxError {
+	SyntaxError::MissingRequiredChild(syntax.clone())
+}
+
+impl TsTypeAliasDecl {
+	pub fn declare_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T![declare])
+	}
+
+	pub fn type_keyword(&self) -> SyntaxResult<SyntaxToken> {
+		contextual_keyword(self.syntax(), "type").ok_or_else(|| missing(self.syntax()))
+	}
+
+	pub fn name(&self) -> SyntaxResult<Name> {
+		support::required_node(self.syntax())
+	}
+
+	pub fn type_parameters(&self) -> Option<TsTypeParams> {
+		support::node(self.syntax())
+	}
+
+	pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T![;])
+	}
+}
+
+impl TsInterfaceDecl {
+	pub fn interface_keyword(&self) -> SyntaxResult<SyntaxToken> {
+		contextual_keyword(self.syntax(), "interface").ok_or_else(|| missing(self.syntax()))
+	}
+
+	pub fn name(&self) -> SyntaxResult<Name> {
+		support::required_node(self.syntax())
+	}
+
+	pub fn type_parameters(&self) -> Option<TsTypeParams> {
+		support::node(self.syntax())
+	}
+
+	/// The interfaces listed after `extends`, `None` if the interface doesn't extend any
+	pub fn extends_list(&self) -> Option<AstSeparatedList<TsExprWithTypeArgs>> {
+		self.extends_token()?;
+		Some(support::separated_list(self.syntax(), 0))
+	}
+
+	pub fn type_members(&self) -> AstNodeList<TsTypeElement> {
+		let index = if self.extends_token().is_some() { 1 } else { 0 };
+		support::node_list(self.syntax(), index)
+	}
 }
 
-impl AstNode for TsModuleRef {
+impl TsTypeElement {
+	/// The `;` or `,` separating the member from the next one, it's part of the member
+	pub fn separator_token(&self) -> Option<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.last()?
+			.into_token()
+			.filter(|token| matches!(token.kind(), T![;] | T![,]))
+	}
+}
+
+/// The key of a property or a method signature, `a` in `a: string` or `Symbol.iterator`
+/// in `[Symbol.iterator](): Iterator<T>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TsSignatureKey {
+	JsReferenceIdentifierMember(JsReferenceIdentifierMember),
+	JsAnyExpression(JsAnyExpression),
+}
+
+impl AstNode for TsSignatureKey {
 	fn can_cast(kind: SyntaxKind) -> bool {
-		kind == TS_EXTERNAL_MODULE_REF || TsEntityName::can_cast(kind)
+		JsReferenceIdentifierMember::can_cast(kind) || JsAnyExpression::can_cast(kind)
 	}
 
 	fn cast(syntax: SyntaxNode) -> Option<Self> {
-		if Self::can_cast(syntax.kind()) {
-			None
-		} else {
-			Some(match syntax.kind() {
-				TS_EXTERNAL_MODULE_REF => TsModuleRef::TsExternalModuleRef(syntax.to()),
-				_ => TsModuleRef::TsEntityName(syntax.to()),
-			})
+		match syntax.kind() {
+			n if JsReferenceIdentifierMember::can_cast(n) => {
+				Some(TsSignatureKey::JsReferenceIdentifierMember(
+					JsReferenceIdentifierMember::cast(syntax).unwrap(),
+				))
+			}
+			_ => Some(TsSignatureKey::JsAnyExpression(JsAnyExpression::cast(
+				syntax,
+			)?)),
 		}
 	}
 
 	fn syntax(&self) -> &SyntaxNode {
 		match self {
-			TsModuleRef::TsExternalModuleRef(it) => it.syntax(),
-			TsModuleRef::TsEntityName(it) => it.syntax(),
+			TsSignatureKey::JsReferenceIdentifierMember(it) => it.syntax(),
+			TsSignatureKey::JsAnyExpression(it) => it.syntax(),
 		}
 	}
 }
+
+impl TsPropertySignature {
+	/// The `[` of a computed key, `[a]: string`
+	pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T!['['])
+	}
+
+	pub fn name(&self) -> SyntaxResult<TsSignatureKey> {
+		support::required_node(self.syntax())
+	}
+
+	pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T![']'])
+	}
+}
+
+impl TsMethodSignature {
+	/// The `[` of a computed key, `[a](): void`
+	pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T!['['])
+	}
+
+	pub fn name(&self) -> SyntaxResult<TsSignatureKey> {
+		support::required_node(self.syntax())
+	}
+
+	pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T![']'])
+	}
+}
+
+impl TsExprWithTypeArgs {
+	pub fn type_args(&self) -> Option<TsTypeArgs> {
+		support::node(self.syntax())
+	}
+}
+
+impl TsEnum {
+	pub fn declare_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T![declare])
+	}
+
+	pub fn name(&self) -> SyntaxResult<Name> {
+		support::required_node(self.syntax())
+	}
+
+	pub fn enum_members(&self) -> AstSeparatedList<TsEnumMember> {
+		support::separated_list(self.syntax(), 0)
+	}
+}
+
+impl TsEnumMember {
+	/// The name of the member, an identifier or a string literal
+	pub fn name_token(&self) -> SyntaxResult<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|x| x.into_token())
+			.find(|x| matches!(x.kind(), IDENT | JS_STRING_LITERAL))
+			.ok_or_else(|| missing(self.syntax()))
+	}
+
+	/// The `=` token and the value of the member, if it has an initializer
+	pub fn initializer(&self) -> Option<(SyntaxToken, JsAnyExpression)> {
+		Some((
+			support::token(self.syntax(), T![=])?,
+			support::node(self.syntax())?,
+		))
+	}
+}
+
+impl TsNamespaceDecl {
+	/// The `namespace` keyword, missing for the nested declarations of `namespace a.b {}`
+	pub fn namespace_keyword(&self) -> Option<SyntaxToken> {
+		contextual_keyword(self.syntax(), "namespace")
+	}
+
+	pub fn name(&self) -> SyntaxResult<Name> {
+		support::required_node(self.syntax())
+	}
+}
+
+impl TsModuleDecl {
+	pub fn global_keyword(&self) -> Option<SyntaxToken> {
+		contextual_keyword(self.syntax(), "global")
+	}
+
+	pub fn module_keyword(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T![module])
+	}
+
+	/// The name of `module a {}`
+	pub fn name(&self) -> Option<Name> {
+		support::node(self.syntax())
+	}
+
+	/// The string literal naming an ambient module, `"a"` in `declare module "a" {}`
+	pub fn string_name_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), JS_STRING_LITERAL)
+	}
+
+	pub fn module_body(&self) -> Option<TsModuleBlock> {
+		support::node(self.syntax())
+	}
+}
+
+impl TsModuleBlock {
+	pub fn statements(&self) -> AstNodeList<JsAnyStatement> {
+		support::node_list(self.syntax(), 0)
+	}
+}
+
+impl TsTypeParams {
+	pub fn param_list(&self) -> AstSeparatedList<TsTypeParam> {
+		support::separated_list(self.syntax(), 0)
+	}
+}
+
+impl TsTypeParam {
+	pub fn name_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(self.syntax(), T![ident])
+	}
+}
+
+impl TsTypeArgs {
+	pub fn arg_list(&self) -> AstSeparatedList<TsType> {
+		support::separated_list(self.syntax(), 0)
+	}
+}
+
+impl TsTypeName {
+	pub fn ident_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(self.syntax(), T![ident])
+	}
+}
+
+impl TsQualifiedPath {
+	/// The name after the dot, `b` in `a.b`
+	pub fn member(&self) -> SyntaxResult<TsTypeName> {
+		self.syntax()
+			.children()
+			.skip(1)
+			.find_map(TsTypeName::cast)
+			.ok_or_else(|| missing(self.syntax()))
+	}
+}
+
+impl TsLiteral {
+	/// The sign of a negative number literal type
+	pub fn minus_token(&self) -> Option<SyntaxToken> {
+		support::token(self.syntax(), T![-])
+	}
+
+	pub fn literal(&self) -> SyntaxResult<JsAnyLiteralExpression> {
+		support::required_node(self.syntax())
+	}
+}
+
+impl TsPredicate {
+	pub fn asserts_keyword(&self) -> Option<SyntaxToken> {
+		contextual_keyword(self.syntax(), "asserts")
+	}
+
+	pub fn is_keyword(&self) -> Option<SyntaxToken> {
+		contextual_keyword(self.syntax(), "is")
+	}
+
+	/// The type after `is`, `string` in `a is string`
+	pub fn predicate_type(&self) -> Option<TsType> {
+		self.is_keyword()?;
+		support::children(self.syntax()).last()
+	}
+}
+
+impl TsTuple {
+	pub fn element_list(&self) -> AstSeparatedList<TsTupleElement> {
+		support::separated_list(self.syntax(), 0)
+	}
+}
+
+impl TsTupleElement {
+	/// The label of a named tuple member, `a` in `[a: string]`
+	pub fn name(&self) -> Option<Name> {
+		support::node(self.syntax())
+	}
+}
+
+impl TsTemplate {
+	pub fn l_tick_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(self.syntax(), BACKTICK)
+	}
+
+	/// The string chunks and the `${type}` elements of the template, in source order
+	pub fn parts(&self) -> impl Iterator<Item = TsTemplatePart> {
+		self.syntax()
+			.children()
+			.find(|child| child.kind() == LIST)
+			.into_iter()
+			.flat_map(|list| list.children_with_tokens())
+			.filter_map(|element| match element {
+				NodeOrToken::Token(token) if token.kind() == TEMPLATE_CHUNK => {
+					Some(TsTemplatePart::Chunk(token))
+				}
+				NodeOrToken::Node(node) => {
+					TsTemplateElement::cast(node).map(TsTemplatePart::Element)
+				}
+				_ => None,
+			})
+	}
+
+	pub fn r_tick_token(&self) -> SyntaxResult<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|element| element.into_token())
+			.filter(|token| token.kind() == BACKTICK)
+			.nth(1)
+			.ok_or_else(|| missing(self.syntax()))
+	}
+}
+
+/// A part of a template literal type, see [TsTemplate::parts]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TsTemplatePart {
+	Chunk(SyntaxToken),
+	Element(TsTemplateElement),
+}
+
+impl TsTemplateElement {
+	pub fn dollar_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(self.syntax(), DOLLARCURLY)
+	}
+}
+
+impl TsMappedTypeParam {
+	/// The name of the type parameter, `K` in `[K in T]`
+	pub fn name_token(&self) -> SyntaxResult<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.filter_map(|x| x.into_token())
+			.find(|x| x.kind() == IDENT && x.text_trimmed() != "as")
+			.ok_or_else(|| missing(self.syntax()))
+	}
+
+	pub fn in_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(self.syntax(), T![in])
+	}
+}
+
+impl TsIndexedArray {
+	/// The index type, `K` in `T[K]`
+	pub fn index_type(&self) -> SyntaxResult<TsType> {
+		support::children(self.syntax())
+			.nth(1)
+			.ok_or_else(|| missing(self.syntax()))
+	}
+}
+
+impl TsTypeOperator {
+	/// `keyof`, `unique` or `readonly`
+	pub fn operator_token(&self) -> SyntaxResult<SyntaxToken> {
+		self.syntax()
+			.children_with_tokens()
+			.find_map(|x| x.into_token())
+			.ok_or_else(|| missing(self.syntax()))
+	}
+}
+
+/// Returns the `|` or `&` tokens of the list of a union or an intersection type, the first
+/// one being the optional leading operator, `|` in `type A = | B | C`
+fn type_operators(syntax: &SyntaxNode) -> (Option<SyntaxToken>, Vec<SyntaxToken>) {
+	let list = match syntax.children().find(|child| child.kind() == LIST) {
+		Some(list) => list,
+		None => return (None, vec![]),
+	};
+
+	let mut leading = None;
+	let mut operators = vec![];
+	let mut seen_type = false;
+
+	for element in list.children_with_tokens() {
+		match element {
+			NodeOrToken::Node(_) => seen_type = true,
+			NodeOrToken::Token(token) if seen_type => operators.push(token),
+			NodeOrToken::Token(token) => leading = Some(token),
+		}
+	}
+
+	(leading, operators)
+}
+
+impl TsUnion {
+	/// The leading `|` of a union, `type A = | B | C`
+	pub fn leading_pipe_token(&self) -> Option<SyntaxToken> {
+		type_operators(self.syntax()).0
+	}
+
+	/// The `|` tokens between the types of the union
+	pub fn pipe_tokens(&self) -> Vec<SyntaxToken> {
+		type_operators(self.syntax()).1
+	}
+}
+
+impl TsIntersection {
+	/// The leading `&` of an intersection, `type A = & B & C`
+	pub fn leading_amp_token(&self) -> Option<SyntaxToken> {
+		type_operators(self.syntax()).0
+	}
+
+	/// The `&` tokens between the types of the intersection
+	pub fn amp_tokens(&self) -> Vec<SyntaxToken> {
+		type_operators(self.syntax()).1
+	}
+}
+
+impl TsFnType {
+	pub fn type_parameters(&self) -> Option<TsTypeParams> {
+		support::node(self.syntax())
+	}
+}
+
+impl TsConstructorType {
+	pub fn fat_arrow_token(&self) -> SyntaxResult<SyntaxToken> {
+		support::required_token(self.syntax(), T![=>])
+	}
+}
+
+impl TsConditionalType {
+	/// The type returned if the condition is true, `C` in `A extends B ? C : D`
+	pub fn true_type(&self) -> SyntaxResult<TsType> {
+		support::children(self.syntax())
+			.nth(1)
+			.ok_or_else(|| missing(self.syntax()))
+	}
+
+	/// The type returned if the condition is false, `D` in `A extends B ? C : D`
+	pub fn false_type(&self) -> SyntaxResult<TsType> {
+		support::children(self.syntax())
+			.nth(2)
+			.ok_or_else(|| missing(self.syntax()))
+	}
+}
+
+impl TsInfer {
+	pub fn name(&self) -> SyntaxResult<Name> {
+		support::required_node(self.syntax())
+	}
+}
+
+impl TsImport {
+	/// The member accessed on the imported module, `B` in `import("a").B`
+	pub fn qualifier_name(&self) -> Option<TsEntityName> {
+		support::node(self.syntax())
+	}
+
+	pub fn type_arguments(&self) -> Option<TsTypeArgs> {
+		support::node(self.syntax())
+	}
+}
@@ -23,10 +23,6 @@ pub(crate) fn parse_binding(p: &mut Parser) -> ParsedSyntax {
 	}
 }
 
-pub(crate) fn parse_binding_with_optional_default(p: &mut Parser) -> ParsedSyntax {
-	BindingWithDefault.parse_pattern_with_optional_default(p)
-}
-
 fn is_at_identifier_binding(p: &Parser) -> bool {
 	is_at_identifier(p)
 }
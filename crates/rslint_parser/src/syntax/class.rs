@@ -577,8 +577,7 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 				let completed = if is_getter {
 					p.expect_required(T![')']);
 					ts_return_type(p);
-					function_body(p)
-						.or_missing_with_error(p, js_parse_error::expected_function_body);
+					class_member_function_body(p);
 
 					member_marker.complete(p, JS_GETTER_CLASS_MEMBER)
 				} else {
@@ -586,8 +585,7 @@ fn class_member(p: &mut Parser) -> CompletedMarker {
 					parse_formal_param_pat(p)
 						.or_missing_with_error(p, js_parse_error::expected_parameter);
 					p.expect_required(T![')']);
-					function_body(p)
-						.or_missing_with_error(p, js_parse_error::expected_function_body);
+					class_member_function_body(p);
 
 					member_marker.complete(p, JS_SETTER_CLASS_MEMBER)
 				};
@@ -752,11 +750,23 @@ fn method_class_member_body(p: &mut Parser, m: Marker) -> CompletedMarker {
 	ts_parameter_types(p);
 	parse_parameter_list(p).or_missing_with_error(p, js_parse_error::expected_parameters);
 	ts_return_type(p);
-	function_body(p).or_missing_with_error(p, js_parse_error::expected_function_body);
+	class_member_function_body(p);
 
 	m.complete(p, JS_METHOD_CLASS_MEMBER)
 }
 
+/// Parses the body of a method, a getter or a setter.
+///
+/// TypeScript allows to omit the body of abstract members, of overloads, and of the members of
+/// ambient classes. The `;` that follows them is parsed as an empty class member.
+fn class_member_function_body(p: &mut Parser) {
+	if p.typescript() && !p.at(T!['{']) && is_semi(p, 0) {
+		return;
+	}
+
+	function_body(p).or_missing_with_error(p, js_parse_error::expected_function_body);
+}
+
 fn constructor_class_member_body(p: &mut Parser, member_marker: Marker) -> CompletedMarker {
 	if let Some(range) = optional_member_token(p) {
 		let err = p
@@ -789,7 +799,8 @@ fn constructor_class_member_body(p: &mut Parser, member_marker: Marker) -> Compl
 		p.error(err);
 	}
 
-	{
+	// Like methods, the constructor overloads of TypeScript have no body
+	if !(p.typescript() && !p.at(T!['{']) && is_semi(p, 0)) {
 		let mut guard = p.with_state(ParserState {
 			in_function: true,
 			in_constructor: true,
@@ -6,11 +6,13 @@ use super::typescript::*;
 #[allow(deprecated)]
 use crate::parser::ParsedSyntax::{Absent, Present};
 use crate::parser::ParserProgress;
-use crate::syntax::binding::parse_binding_with_optional_default;
 use crate::syntax::function::function_body;
 use crate::syntax::js_parse_error;
 use crate::syntax::js_parse_error::expected_binding;
+use crate::syntax::pattern::ParseWithDefaultPattern;
 use crate::{SyntaxKind::*, *};
+use rslint_errors::Diagnostic;
+use std::ops::Range;
 
 #[allow(clippy::unnecessary_unwrap)]
 pub(super) fn parse_formal_param_pat(p: &mut Parser) -> ParsedSyntax {
@@ -24,7 +26,29 @@ pub(super) fn parse_formal_param_pat(p: &mut Parser) -> ParsedSyntax {
 		}
 	}
 
-	parse_binding_with_optional_default(p)
+	ParameterBinding.parse_pattern_with_optional_default(p)
+}
+
+/// A parameter binding, with its type annotation in TypeScript files: `a?: string`
+struct ParameterBinding;
+
+impl ParseWithDefaultPattern for ParameterBinding {
+	#[inline]
+	fn pattern_with_default_kind() -> SyntaxKind {
+		JS_BINDING_WITH_DEFAULT
+	}
+
+	#[inline]
+	fn expected_pattern_error(p: &Parser, range: Range<usize>) -> Diagnostic {
+		expected_binding(p, range)
+	}
+
+	fn parse_pattern(&self, p: &mut Parser) -> ParsedSyntax {
+		match parse_binding(p) {
+			Present(binding) => Present(ts_binding_annotation(p, binding)),
+			Absent => Absent,
+		}
+	}
 }
 
 // test parameter_list
@@ -67,27 +91,21 @@ pub(super) fn parse_parameters_list(
 		if p.at(T![...]) {
 			let m = p.start();
 			p.bump_any();
-			parse_binding(p).or_missing_with_error(p, expected_binding);
-
-			// TODO #1725 Review error handling and recovery
-			// rest patterns cannot be optional: `...foo?: number[]`
-			if p.at(T![?]) {
-				let err = p
-					.err_builder("rest patterns cannot be optional")
-					.primary(p.cur_tok().range, "");
+			let binding = parse_binding(p).or_missing_with_error(p, expected_binding);
 
-				p.error(err);
-				let m = p.start();
-				p.bump_any();
-				m.complete(p, JS_UNKNOWN_BINDING);
-			}
+			if let Some(binding) = binding {
+				// TODO #1725 Review error handling and recovery
+				// rest patterns cannot be optional: `...foo?: number[]`
+				if p.at(T![?]) {
+					let err = p
+						.err_builder("rest patterns cannot be optional")
+						.primary(p.cur_tok().range, "");
 
-			// type annotation `...foo: number[]`
-			if p.eat(T![:]) {
-				let complete = ts_type(p);
-				if let Some(mut res) = complete {
-					res.err_if_not_ts(p, "type annotations can only be used in TypeScript files");
+					p.error(err);
 				}
+
+				// type annotation `...foo: number[]`
+				ts_binding_annotation(p, binding);
 			}
 
 			if p.at(T![=]) {
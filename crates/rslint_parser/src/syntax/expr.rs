@@ -440,12 +440,14 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
 	// test_err subscripts_err
 	// foo()?.baz[].
 	// BAR`b
-	let mut should_try_parsing_ts = true;
 	let mut progress = ParserProgress::default();
 	while !p.at(EOF) {
 		progress.assert_progressing(p);
 
 		match p.cur() {
+			// test optional_call_expr
+			// foo?.()
+			// foo?.bar?.(baz)
 			T![?.] if p.nth_at(1, T!['(']) => {
 				lhs = {
 					let m = lhs.precede(p);
@@ -481,11 +483,14 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
 					comp
 				}
 			}
-			T![<] if p.typescript() && should_try_parsing_ts => {
+			T![<] if p.typescript() => {
 				let res = try_parse_ts(p, |p| {
 					let m = lhs.precede(p);
 					// TODO: handle generic async arrow function expressions
-					ts_type_args(p)?;
+					if ts_type_args(p).is_none() {
+						m.abandon(p);
+						return None;
+					}
 					if !no_call && p.at(T!['(']) {
 						args(p);
 						Some(m.complete(p, CALL_EXPR))
@@ -493,11 +498,14 @@ pub fn subscripts(p: &mut Parser, mut lhs: CompletedMarker, no_call: bool) -> Co
 						m.abandon(p);
 						Some(template(p, Some(lhs)))
 					} else {
+						m.abandon(p);
 						None
 					}
 				});
-				if res.is_none() {
-					should_try_parsing_ts = false;
+				match res {
+					Some(res) => lhs = res,
+					// The `<` is a binary operator
+					None => return lhs,
 				}
 			}
 			BACKTICK => lhs = template(p, Some(lhs)),
@@ -781,6 +789,7 @@ pub fn paren_or_arrow_expr(p: &mut Parser, can_be_arrow: bool) -> CompletedMarke
 			}
 
 			if p.at(T![:]) {
+				let return_type = p.start();
 				let complete = ts_type_or_type_predicate_ann(p, T![:]);
 				if let Some(mut complete) = complete {
 					complete.err_if_not_ts(
@@ -788,6 +797,7 @@ pub fn paren_or_arrow_expr(p: &mut Parser, can_be_arrow: bool) -> CompletedMarke
 						"arrow functions can only have return types in TypeScript files",
 					);
 				}
+				return_type.complete(p, TS_TYPE_ANNOTATION);
 			}
 
 			p.bump_any();
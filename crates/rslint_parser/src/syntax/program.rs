@@ -16,12 +16,13 @@ use syntax::stmt::FOLLOWS_LET;
 
 #[macro_export]
 macro_rules! at_ident_name {
-    ($p:expr) => {
-        ($p.at_ts(token_set![T![ident], T![await], T![yield]]) || $p.cur().is_keyword())
-    };
-    ($p:expr, $offset:expr) => {
-        (token_set![T![ident], T![await], T![yield]].contains($p.nth($offset)) || $p.nth($offset).is_keyword())
-    }
+	($p:expr) => {
+		($p.at_ts(token_set![T![ident], T![await], T![yield]]) || $p.cur().is_keyword())
+	};
+	($p:expr, $offset:expr) => {
+		(token_set![T![ident], T![await], T![yield]].contains($p.nth($offset))
+			|| $p.nth($offset).is_keyword())
+	};
 }
 
 pub fn parse(p: &mut Parser) -> CompletedMarker {
@@ -126,9 +127,9 @@ pub fn import_decl(p: &mut Parser) -> CompletedMarker {
 		let inner = p.start();
 		p.bump_any();
 		inner.complete(p, IMPORT_STRING_SPECIFIER);
-		semi(p, start..p.cur_tok().range.start);
-
 		list.complete(p, LIST);
+
+		semi(p, start..p.cur_tok().range.start);
 		return m.complete(p, IMPORT_DECL);
 	}
 
@@ -454,10 +455,11 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 	{
 		variable_declaration_statement(p).unwrap();
 	} else {
-		let m = p.start();
+		let named = p.start();
 
 		if p.cur_src() == "from" && exports_ns {
 			from_clause_and_semi(p, start);
+			named.abandon(p);
 			return m.complete(p, EXPORT_WILDCARD);
 		}
 
@@ -471,11 +473,13 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 
 		if p.cur_src() == "from" && export_default {
 			from_clause_and_semi(p, start);
-			return m.complete(p, EXPORT_NAMED);
+			named.complete(p, EXPORT_NAMED);
+			return m.complete(p, EXPORT_DECL);
 		}
 
 		if has_star && !exports_ns {
 			from_clause_and_semi(p, start);
+			named.abandon(p);
 			return m.complete(p, EXPORT_WILDCARD);
 		}
 
@@ -518,7 +522,7 @@ pub fn export_decl(p: &mut Parser) -> CompletedMarker {
 			}
 		}
 
-		m.complete(p, EXPORT_NAMED);
+		named.complete(p, EXPORT_NAMED);
 	}
 	m.complete(p, EXPORT_DECL)
 }
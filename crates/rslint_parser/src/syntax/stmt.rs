@@ -818,23 +818,11 @@ pub(crate) fn variable_declarator(
 			p.error(err);
 		}
 
-		if p.eat(T![:]) {
-			let start = p.cur_tok().range.start;
-			let ty = ts_type(p);
-			let end = ty
-				.map(|x| usize::from(x.range(p).end()))
-				.unwrap_or(p.cur_tok().range.start);
-			if !p.typescript() {
-				let err = p
-					.err_builder("type annotations can only be used in TypeScript files")
-					.primary(start..end, "");
-
-				p.error(err);
-			}
+		if let Some(range) = maybe_ts_type_annotation(p) {
 			if p.typescript() && for_stmt {
 				let err = p
 					.err_builder("`for` statement declarators cannot have a type annotation")
-					.primary(start..end, "");
+					.primary(range, "");
 
 				p.state.for_head_error = Some(err);
 			}
@@ -1220,12 +1208,11 @@ fn parse_catch_declaration(p: &mut Parser) -> ParsedSyntax {
 	p.bump_any(); // bump (
 
 	let pattern_marker = parse_binding(p).or_missing_with_error(p, expected_binding);
-	let pattern_kind = pattern_marker.map(|x| x.kind());
 
 	if p.at(T![:]) {
-		let error_marker = pattern_marker
-			.map(|m| m.precede(p))
-			.unwrap_or_else(|| p.start());
+		// The type annotation is part of the binding, like the ones of the variable declarators
+		let pattern = pattern_marker.map(|pattern| (pattern.kind(), pattern.undo_completion(p)));
+		let annotation = p.start();
 		let start = p.cur_tok().range.start;
 		p.bump_any();
 		let ty = ts_type(p);
@@ -1244,7 +1231,18 @@ fn parse_catch_declaration(p: &mut Parser) -> ParsedSyntax {
 		let end = ty
 			.map(|x| usize::from(x.range(p).end()))
 			.unwrap_or(p.cur_tok().range.start);
-		error_marker.complete(p, pattern_kind.filter(|_| p.typescript()).unwrap_or(ERROR));
+		annotation.complete(
+			p,
+			if p.typescript() {
+				TS_TYPE_ANNOTATION
+			} else {
+				ERROR
+			},
+		);
+
+		if let Some((kind, pattern)) = pattern {
+			pattern.complete(p, kind);
+		}
 
 		if !p.typescript() {
 			let err = p
@@ -101,6 +101,31 @@ pub fn ts_modifier(p: &mut Parser, modifiers: &[&'static str]) -> Option<Range<u
 	Some(range)
 }
 
+/// Parses the `?` of an optional parameter and the type annotation that follow a binding,
+/// `a?: string`, and makes them part of the binding node.
+pub(crate) fn ts_binding_annotation(p: &mut Parser, binding: CompletedMarker) -> CompletedMarker {
+	if !p.at(T![?]) && !p.at(T![:]) {
+		return binding;
+	}
+
+	let kind = binding.kind();
+	let m = binding.undo_completion(p);
+
+	if p.at(T![?]) {
+		if !p.typescript() {
+			let err = p
+				.err_builder("optional parameters can only be used in TypeScript files")
+				.primary(p.cur_tok().range, "");
+
+			p.error(err);
+		}
+		p.bump_any();
+	}
+
+	maybe_ts_type_annotation(p);
+	m.complete(p, kind)
+}
+
 pub(crate) fn maybe_ts_type_annotation(p: &mut Parser) -> Option<Range<usize>> {
 	if p.at(T![:]) {
 		let m = p.start();
@@ -150,7 +175,15 @@ pub(crate) fn ts_declare(p: &mut Parser) -> Option<CompletedMarker> {
 		..p.state.clone()
 	});
 	Some(match p.nth(1) {
-		T![function] => parse_function_declaration(p).unwrap(),
+		T![function] => {
+			let m = p.start();
+			p.bump_remap(T![declare]);
+			parse_function_declaration(p)
+				.unwrap()
+				.undo_completion(p)
+				.abandon(p);
+			m.complete(p, JS_FUNCTION_DECLARATION)
+		}
 		T![class] => {
 			let m = p.start();
 			p.bump_remap(T![declare]);
@@ -158,7 +191,7 @@ pub(crate) fn ts_declare(p: &mut Parser) -> Option<CompletedMarker> {
 				.unwrap()
 				.undo_completion(p)
 				.abandon(p);
-			m.complete(p, JS_LITERAL_MEMBER_NAME)
+			m.complete(p, JS_CLASS_DECLARATION)
 		}
 		t if (t == T![const] && p.nth_at(2, T![enum])) || t == T![enum] => {
 			let m = p.start();
@@ -221,13 +254,13 @@ pub(crate) fn ts_decl(p: &mut Parser) -> Option<CompletedMarker> {
                 .primary(range, "");
 
 			p.error(err);
-			return None;
+			return Some(m.complete(p, ERROR));
 		}
 		parse_class_declaration(p)
 			.unwrap()
 			.undo_completion(p)
 			.abandon(p);
-		return Some(m.complete(p, JS_LITERAL_MEMBER_NAME));
+		return Some(m.complete(p, JS_CLASS_DECLARATION));
 	}
 
 	if p.at(T![enum]) {
@@ -242,18 +275,34 @@ pub(crate) fn ts_decl(p: &mut Parser) -> Option<CompletedMarker> {
 		if p.nth_at(1, JS_STRING_LITERAL) {
 			return ts_ambient_external_module_decl(p, true);
 		} else if token_set![T![ident], T![yield], T![await]].contains(p.nth(1)) {
+			let m = p.start();
 			p.bump_remap(T![module]);
-			return ts_module_or_namespace_decl(p, false, false);
+			return match ts_module_or_namespace_decl(p, false, false) {
+				Some(decl) => {
+					decl.undo_completion(p).abandon(p);
+					Some(m.complete(p, TS_MODULE_DECL))
+				}
+				None => {
+					m.abandon(p);
+					None
+				}
+			};
 		}
 	}
 
 	if p.cur_src() == "namespace" {
 		let m = p.start();
 		p.bump_any();
-		ts_module_or_namespace_decl(p, true, false)?
-			.undo_completion(p)
-			.abandon(p);
-		return Some(m.complete(p, TS_NAMESPACE_DECL));
+		return match ts_module_or_namespace_decl(p, true, false) {
+			Some(decl) => {
+				decl.undo_completion(p).abandon(p);
+				Some(m.complete(p, TS_NAMESPACE_DECL))
+			}
+			None => {
+				m.abandon(p);
+				None
+			}
+		};
 	}
 
 	if p.cur_src() == "type" {
@@ -559,13 +608,13 @@ pub(crate) fn try_parse_index_signature(
 		.unwrap()
 		.undo_completion(p);
 
-	if p.expect_no_recover(T![:]).is_none() {
-		return Err(m);
-	}
-
-	if ts_type(p).is_none() && p.state.no_recovery {
+	let annotation = p.start();
+	if p.expect_no_recover(T![:]).is_none() || (ts_type(p).is_none() && p.state.no_recovery) {
+		annotation.abandon(p);
+		pat_m.abandon(p);
 		return Err(m);
 	}
+	annotation.complete(p, TS_TYPE_ANNOTATION);
 
 	pat_m.complete(p, JS_IDENTIFIER_BINDING);
 
@@ -936,6 +985,7 @@ pub fn ts_array_type_or_higher(p: &mut Parser) -> Option<CompletedMarker> {
 pub fn ts_tuple(p: &mut Parser) -> Option<CompletedMarker> {
 	let m = p.start();
 	p.expect_no_recover(T!['['])?;
+	let elements_list = p.start();
 	let mut progress = ParserProgress::default();
 	while !p.at(EOF) && !p.at(T![']']) {
 		progress.assert_progressing(p);
@@ -970,8 +1020,11 @@ pub fn ts_tuple(p: &mut Parser) -> Option<CompletedMarker> {
 
 			p.error(err);
 		}
-		p.eat(T![,]);
+		if !p.at(T![']']) {
+			p.expect_required(T![,]);
+		}
 	}
+	elements_list.complete(p, LIST);
 
 	p.expect_no_recover(T![']'])?;
 	Some(m.complete(p, TS_TUPLE))
@@ -1351,7 +1404,8 @@ fn is_mapped_type_start(p: &Parser) -> bool {
 		return true;
 	}
 	let mut cur = 1;
-	if p.cur_src() == "readonly" {
+	// `{ readonly [K in T]: U }`, the current token is the `{`
+	if p.nth_src(1) == "readonly" {
 		cur += 1;
 	}
 	if !p.nth_at(cur, T!['[']) {
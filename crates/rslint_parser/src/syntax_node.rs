@@ -35,6 +35,7 @@ pub type SyntaxElementChildren = rome_rowan::SyntaxElementChildren<JsLanguage>;
 pub type SyntaxList = rome_rowan::SyntaxList<JsLanguage>;
 pub type SyntaxSlots = rome_rowan::SyntaxSlots<JsLanguage>;
 pub type SyntaxSlot = rome_rowan::SyntaxSlot<JsLanguage>;
+pub type SyntaxTriviaPiece = rome_rowan::SyntaxTriviaPiece<JsLanguage>;
 
 pub use rome_rowan::{Direction, NodeOrToken};
 
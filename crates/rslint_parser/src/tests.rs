@@ -1,5 +1,8 @@
 use crate::ast::{ArgList, JsRoot};
-use crate::{parse_module, parse_text, AstNode, Parse, ParserError, SyntaxNode, SyntaxToken};
+use crate::{
+	parse_module, parse_text, parse_with_syntax, AstNode, Parse, ParserError, Syntax, SyntaxNode,
+	SyntaxToken, T,
+};
 use expect_test::expect_file;
 use rome_rowan::TextSize;
 use rslint_errors::file::SimpleFile;
@@ -325,3 +328,84 @@ pub fn just_trivia_must_be_appended_to_eof() {
 	assert_eq!(TextSize::from(0), start);
 	assert_eq!(TextSize::from(34), end);
 }
+
+fn parse_typescript(text: &str) -> SyntaxNode {
+	let parse = parse_with_syntax(text, 0, Syntax::default().typescript());
+	assert_errors_are_absent(parse.errors(), Path::new("test.ts"), &parse.syntax());
+	parse.syntax()
+}
+
+fn find_kind(root: &SyntaxNode, kind: SyntaxKind) -> SyntaxNode {
+	root.descendants()
+		.find(|node| node.kind() == kind)
+		.unwrap_or_else(|| panic!("no {:?} node in {:#?}", kind, root))
+}
+
+#[test]
+pub fn ts_arrow_return_type_is_an_annotation() {
+	let root = parse_typescript("(a): string => a");
+	let arrow = find_kind(&root, SyntaxKind::JS_ARROW_FUNCTION_EXPRESSION);
+	let annotation = find_kind(&arrow, SyntaxKind::TS_TYPE_ANNOTATION);
+
+	assert_eq!(annotation.text_trimmed(), ": string");
+	assert_eq!(annotation.parent().as_ref(), Some(&arrow));
+}
+
+#[test]
+pub fn ts_binding_annotations_belong_to_the_binding() {
+	let root =
+		parse_typescript("function f(a?: string, ...b: number[]) {}\ntry {} catch (e: unknown) {}");
+
+	let bindings: Vec<_> = root
+		.descendants()
+		.filter(|node| node.kind() == SyntaxKind::JS_IDENTIFIER_BINDING)
+		.map(|node| node.text_trimmed().to_string())
+		.collect();
+
+	assert_eq!(bindings, ["f", "a?: string", "b: number[]", "e: unknown"]);
+}
+
+#[test]
+pub fn ts_declare_function_and_class_are_declarations() {
+	let root = parse_typescript("declare function f(): void;\ndeclare class A { m(): void; }");
+
+	let function = find_kind(&root, SyntaxKind::JS_FUNCTION_DECLARATION);
+	assert_eq!(function.first_token().unwrap().kind(), T![declare]);
+
+	let class = find_kind(&root, SyntaxKind::JS_CLASS_DECLARATION);
+	assert_eq!(class.first_token().unwrap().kind(), T![declare]);
+}
+
+#[test]
+pub fn ts_overloads_have_no_body() {
+	let root = parse_typescript("class A {\n\tconstructor();\n\tm(a: string): void;\n\tm(a) {}\n}");
+
+	let methods = root
+		.descendants()
+		.filter(|node| node.kind() == SyntaxKind::JS_METHOD_CLASS_MEMBER)
+		.count();
+	assert_eq!(methods, 2);
+}
+
+#[test]
+pub fn ts_less_than_after_failed_type_args_is_a_binary_operator() {
+	let root = parse_typescript("a < b;\nf<string>(c) < d;");
+
+	let binary = root
+		.descendants()
+		.filter(|node| node.kind() == SyntaxKind::JS_BINARY_EXPRESSION)
+		.count();
+	assert_eq!(binary, 2);
+}
+
+#[test]
+pub fn js_rejects_type_annotations() {
+	for text in [
+		"(a): string => a",
+		"function f(a?: string) {}",
+		"try {} catch (e: unknown) {}",
+	] {
+		let parse = parse_module(text, 0);
+		assert!(!parse.errors().is_empty(), "expected errors in `{}`", text);
+	}
+}
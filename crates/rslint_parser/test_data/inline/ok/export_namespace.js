@@ -0,0 +1 @@
+export * as foo from "bla";
@@ -68,7 +68,8 @@ impl Arguments {
 		let path = path.as_ref();
 		let file_stem = path.file_stem()?;
 		let file_stem = file_stem.to_str()?;
-		let test_name = file_stem.to_snake();
+		// The stem of `a.d.ts` is `a.d`, the dot isn't valid in the name of a test
+		let test_name = file_stem.replace('.', "_").to_snake();
 
 		let test_full_path = path.display().to_string();
 